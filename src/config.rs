@@ -1,12 +0,0 @@
-use bevy::prelude::{Plugin as BevyPlugin, *};
-
-pub struct Plugin;
-
-impl BevyPlugin for Plugin {
-    fn build(&self, app: &mut App) {
-        app.insert_resource(WindowDescriptor {
-            title: "Egg fetcher".to_string(),
-            ..Default::default()
-        });
-    }
-}
@@ -0,0 +1,225 @@
+use std::{fmt, str::FromStr};
+
+const USAGE: &str = "Usage: egg_fetcher [OPTIONS]
+
+Options:
+    --level <id>       Start the given level
+    --seed <n>         Seed the game random number generator
+    --windowed <WxH>   Run in a window of the given size, e.g. 1280x720
+    --skip-title       Go straight from loading into play
+    --screen-shake <f> Scale screen shake, from 0 (off) to 1 (full)
+    --minimap <px>     Width of the minimap, 0 starts with it hidden
+    -h, --help         Print this message";
+
+#[derive(Debug)]
+pub enum Error {
+    Help,
+    MissingValue(String),
+    InvalidValue(String, String),
+    UnknownFlag(String),
+    /// `--level` named a level that doesn't exist.
+    UnknownLevel(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Help => write!(f, "{USAGE}"),
+            Error::MissingValue(flag) => write!(f, "missing value for `{flag}`\n\n{USAGE}"),
+            Error::InvalidValue(flag, value) => {
+                write!(f, "invalid value `{value}` for `{flag}`\n\n{USAGE}")
+            }
+            Error::UnknownFlag(flag) => write!(f, "unknown flag `{flag}`\n\n{USAGE}"),
+            Error::UnknownLevel(level) => write!(
+                f,
+                "there is no level {level}, pick one from 1 to {}\n\n{USAGE}",
+                crate::scenes::levels().count()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowSize {
+    pub width: f32,
+    pub height: f32,
+}
+
+impl FromStr for WindowSize {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<WindowSize, ()> {
+        let (width, height) = value.split_once(['x', 'X']).ok_or(())?;
+        let width = width.parse::<u32>().map_err(|_| ())?;
+        let height = height.parse::<u32>().map_err(|_| ())?;
+
+        if width == 0 || height == 0 {
+            return Err(());
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        Ok(WindowSize {
+            width: width as f32,
+            height: height as f32,
+        })
+    }
+}
+
+/// Options passed on the command line, inserted as a resource before any
+/// plugin is built.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    pub level: Option<usize>,
+    pub seed: Option<u64>,
+    pub windowed: Option<WindowSize>,
+    pub skip_title: bool,
+    pub screen_shake: Option<f32>,
+    pub minimap_width: Option<u32>,
+}
+
+impl Args {
+    /// Parses the arguments the process was started with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when a flag is unknown, is missing its value, or its
+    /// value can't be parsed, or `--level` names a level that doesn't exist.
+    /// `--help` is reported as [`Error::Help`].
+    pub fn from_env() -> Result<Args, Error> {
+        Args::parse(std::env::args().skip(1))
+    }
+
+    /// Parses `args`, which must not include the program name.
+    ///
+    /// # Errors
+    ///
+    /// See [`Args::from_env`].
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Args, Error> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--level" => {
+                    let level = value(&flag, args.next())?;
+                    if !crate::scenes::levels().any(|known| known == level) {
+                        return Err(Error::UnknownLevel(level));
+                    }
+                    parsed.level = Some(level);
+                }
+                "--seed" => parsed.seed = Some(value(&flag, args.next())?),
+                "--windowed" => parsed.windowed = Some(value(&flag, args.next())?),
+                "--screen-shake" => {
+                    let intensity: f32 = value(&flag, args.next())?;
+                    if !(0. ..=1.).contains(&intensity) {
+                        return Err(Error::InvalidValue(flag, intensity.to_string()));
+                    }
+                    parsed.screen_shake = Some(intensity);
+                }
+                "--minimap" => parsed.minimap_width = Some(value(&flag, args.next())?),
+                "--skip-title" => parsed.skip_title = true,
+                "-h" | "--help" => return Err(Error::Help),
+                _ => return Err(Error::UnknownFlag(flag)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+fn value<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, Error> {
+    let value = value.ok_or_else(|| Error::MissingValue(flag.to_string()))?;
+
+    value
+        .parse()
+        .map_err(|_| Error::InvalidValue(flag.to_string(), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        Args::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parses_flags() {
+        let args = parse(&[
+            "--level",
+            "1",
+            "--seed",
+            "42",
+            "--skip-title",
+            "--minimap",
+            "0",
+        ])
+        .unwrap();
+
+        assert_eq!(args.level, Some(1));
+        assert_eq!(args.seed, Some(42));
+        assert!(args.skip_title);
+        assert_eq!(args.minimap_width, Some(0));
+        assert_eq!(args.windowed, None);
+    }
+
+    #[test]
+    fn parses_window_size() {
+        let args = parse(&["--windowed", "1280x720"]).unwrap();
+        assert_eq!(
+            args.windowed,
+            Some(WindowSize {
+                width: 1280.,
+                height: 720.
+            })
+        );
+
+        assert!(matches!(
+            parse(&["--windowed", "1280"]),
+            Err(Error::InvalidValue(..))
+        ));
+        assert!(matches!(
+            parse(&["--windowed", "0x720"]),
+            Err(Error::InvalidValue(..))
+        ));
+    }
+
+    #[test]
+    fn rejects_screen_shake_out_of_range() {
+        assert_eq!(
+            parse(&["--screen-shake", "0.5"]).unwrap().screen_shake,
+            Some(0.5)
+        );
+        assert!(matches!(
+            parse(&["--screen-shake", "1.5"]),
+            Err(Error::InvalidValue(..))
+        ));
+        assert!(matches!(
+            parse(&["--screen-shake", "-0.1"]),
+            Err(Error::InvalidValue(..))
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_levels() {
+        assert!(matches!(
+            parse(&["--level", "0"]),
+            Err(Error::UnknownLevel(0))
+        ));
+        assert!(matches!(
+            parse(&["--level", "99"]),
+            Err(Error::UnknownLevel(99))
+        ));
+    }
+
+    #[test]
+    fn reports_bad_flags() {
+        assert!(matches!(parse(&["--level"]), Err(Error::MissingValue(_))));
+        assert!(matches!(
+            parse(&["--level", "two"]),
+            Err(Error::InvalidValue(..))
+        ));
+        assert!(matches!(parse(&["--fly"]), Err(Error::UnknownFlag(_))));
+        assert!(matches!(parse(&["--help"]), Err(Error::Help)));
+    }
+}
@@ -0,0 +1,47 @@
+mod args;
+
+pub use args::{Args, Error as ArgsError, WindowSize};
+
+use bevy::{
+    prelude::{Plugin as BevyPlugin, *},
+    window::WindowMode,
+};
+
+/// Comfort settings applied on top of the regular game feel.
+#[derive(Debug, Clone)]
+pub struct Accessibility {
+    /// Multiplier for camera shake, `0.` disables it.
+    pub screen_shake: f32,
+}
+
+impl Default for Accessibility {
+    fn default() -> Accessibility {
+        Accessibility { screen_shake: 1. }
+    }
+}
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        let args = app.world.get_resource_or_insert_with(Args::default).clone();
+
+        let mut window = WindowDescriptor {
+            title: "Egg fetcher".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(size) = args.windowed {
+            window.width = size.width;
+            window.height = size.height;
+            window.mode = WindowMode::Windowed;
+        }
+
+        let mut accessibility = Accessibility::default();
+        if let Some(screen_shake) = args.screen_shake {
+            accessibility.screen_shake = screen_shake;
+        }
+
+        app.insert_resource(window).insert_resource(accessibility);
+    }
+}
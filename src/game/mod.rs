@@ -1,3 +1,25 @@
+mod save;
+mod scoped;
 mod state;
 
+use bevy::prelude::{Plugin as BevyPlugin, *};
+
+use crate::config::Args;
+
+pub use save::{LevelRecord, LevelResult, SaveData};
+pub use scoped::StateScoped;
 pub use state::State;
+
+/// Level started when play begins, picked on the level select screen.
+pub struct SelectedLevel(pub usize);
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        let level = app.world.get_resource::<Args>().and_then(|args| args.level);
+
+        app.insert_resource(SelectedLevel(level.unwrap_or(1)))
+            .add_system_to_stage(CoreStage::PostUpdate, scoped::despawn_scoped::<State>);
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+const FILE_NAME: &str = "save.txt";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LevelRecord {
+    pub best_score: usize,
+    pub best_time_left: Duration,
+    pub stars: u8,
+}
+
+/// Outcome of a single attempt at a level.
+#[derive(Debug, Clone)]
+pub struct LevelResult {
+    pub level: usize,
+    /// Points the delivered eggs were worth.
+    pub score: usize,
+    /// Eggs delivered, rotten ones don't count.
+    pub eggs: usize,
+    pub time_left: Duration,
+    pub stars: u8,
+    /// Extra score from selling crops, it isn't part of `score`.
+    pub crop_bonus: usize,
+    pub completed: bool,
+    pub chickens_rescued: usize,
+}
+
+/// Best results and lifetime stats persisted between sessions.
+#[derive(Debug, Clone, Default)]
+pub struct SaveData {
+    pub levels: BTreeMap<usize, LevelRecord>,
+    pub total_eggs: usize,
+    pub total_chickens_rescued: usize,
+}
+
+impl SaveData {
+    /// Loads the save file, falling back to empty data when it is missing or
+    /// unreadable.
+    #[must_use]
+    pub fn load() -> SaveData {
+        fs::read_to_string(path())
+            .map(|contents| SaveData::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// Writes the save file atomically by writing a temporary file next to it
+    /// and renaming it over the old one.
+    ///
+    /// # Errors
+    ///
+    /// Returns any IO error raised while creating, writing or renaming the file.
+    pub fn store(&self) -> io::Result<()> {
+        let path = path();
+        let tmp_path = path.with_extension("tmp");
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(self.serialize().as_bytes())?;
+        file.sync_all()?;
+
+        fs::rename(tmp_path, path)
+    }
+
+    #[must_use]
+    pub fn level(&self, level: usize) -> Option<&LevelRecord> {
+        self.levels.get(&level)
+    }
+
+    /// Lifetime stats, as shown on the title and level select screens.
+    #[must_use]
+    pub fn totals_text(&self) -> String {
+        format!(
+            "Eggs collected: {}   Chickens rescued: {}",
+            self.total_eggs, self.total_chickens_rescued
+        )
+    }
+
+    /// Best result on a level, as shown on the level select and results
+    /// screens.
+    #[must_use]
+    pub fn record_text(&self, level: usize) -> String {
+        match self.level(level) {
+            Some(record) => format!(
+                "Best: {} points, {} seconds left, {}/3 stars",
+                record.best_score,
+                record.best_time_left.as_secs_f32().floor(),
+                record.stars
+            ),
+            None => "Not completed yet".to_string(),
+        }
+    }
+
+    /// Adds the result to the lifetime stats and keeps the best values for
+    /// completed levels.
+    pub fn record(&mut self, result: &LevelResult) {
+        self.total_eggs += result.eggs;
+
+        if !result.completed {
+            return;
+        }
+
+        self.total_chickens_rescued += result.chickens_rescued;
+
+        let record = self.levels.entry(result.level).or_default();
+        record.best_score = record.best_score.max(result.score);
+        record.best_time_left = record.best_time_left.max(result.time_left);
+        record.stars = record.stars.max(result.stars);
+    }
+
+    fn parse(contents: &str) -> SaveData {
+        let mut save = SaveData::default();
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let mut parts = key.trim().split('.');
+
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("total_eggs"), None, None) => {
+                    save.total_eggs = value.trim().parse().unwrap_or_default();
+                }
+                (Some("total_chickens_rescued"), None, None) => {
+                    save.total_chickens_rescued = value.trim().parse().unwrap_or_default();
+                }
+                (Some("level"), Some(level), Some(field)) => {
+                    let Ok(level) = level.parse() else {
+                        continue;
+                    };
+                    let record = save.levels.entry(level).or_default();
+                    let value = value.trim();
+
+                    match field {
+                        "best_score" => record.best_score = value.parse().unwrap_or_default(),
+                        "best_time_left" => {
+                            record.best_time_left = value
+                                .parse()
+                                .map(Duration::from_secs_f32)
+                                .unwrap_or_default();
+                        }
+                        "stars" => record.stars = value.parse().unwrap_or_default(),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        save
+    }
+
+    fn serialize(&self) -> String {
+        let mut contents = format!(
+            "total_eggs={}\ntotal_chickens_rescued={}\n",
+            self.total_eggs, self.total_chickens_rescued
+        );
+
+        for (level, record) in &self.levels {
+            let _ = write!(
+                contents,
+                "level.{level}.best_score={}\nlevel.{level}.best_time_left={}\nlevel.{level}.stars={}\n",
+                record.best_score,
+                record.best_time_left.as_secs_f32(),
+                record.stars
+            );
+        }
+
+        contents
+    }
+}
+
+fn path() -> PathBuf {
+    let data_dir = env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/share")));
+
+    match data_dir {
+        Some(dir) => dir.join("egg_fetcher").join(FILE_NAME),
+        None => PathBuf::from(FILE_NAME),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(score: usize, time_left: u64, stars: u8, completed: bool) -> LevelResult {
+        LevelResult {
+            level: 1,
+            score,
+            eggs: score / 2,
+            time_left: Duration::from_secs(time_left),
+            stars,
+            crop_bonus: 0,
+            completed,
+            chickens_rescued: 1,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_file_format() {
+        let mut save = SaveData::default();
+        save.record(&result(12, 40, 2, true));
+        save.levels.insert(
+            3,
+            LevelRecord {
+                best_score: 7,
+                best_time_left: Duration::from_millis(2500),
+                stars: 1,
+            },
+        );
+
+        let loaded = SaveData::parse(&save.serialize());
+
+        assert_eq!(loaded.total_eggs, 6);
+        assert_eq!(loaded.total_chickens_rescued, 1);
+        assert_eq!(loaded.levels, save.levels);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let save = SaveData::parse("total_eggs=4\nnonsense\nlevel.x.stars=3\nlevel.2.stars=3\n");
+
+        assert_eq!(save.total_eggs, 4);
+        assert_eq!(save.levels.len(), 1);
+        assert_eq!(save.level(2).map(|record| record.stars), Some(3));
+    }
+
+    #[test]
+    fn record_keeps_best_values() {
+        let mut save = SaveData::default();
+
+        save.record(&result(10, 30, 2, true));
+        save.record(&result(15, 10, 1, true));
+        save.record(&result(8, 45, 3, true));
+
+        let record = save.level(1).unwrap();
+        assert_eq!(record.best_score, 15);
+        assert_eq!(record.best_time_left, Duration::from_secs(45));
+        assert_eq!(record.stars, 3);
+        assert_eq!(save.total_eggs, 5 + 7 + 4);
+        assert_eq!(save.total_chickens_rescued, 3);
+    }
+
+    #[test]
+    fn failed_attempts_only_count_eggs() {
+        let mut save = SaveData::default();
+
+        save.record(&result(20, 0, 0, false));
+
+        assert_eq!(save.total_eggs, 10);
+        assert_eq!(save.total_chickens_rescued, 0);
+        assert!(save.level(1).is_none());
+    }
+}
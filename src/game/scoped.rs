@@ -0,0 +1,24 @@
+use bevy::{ecs::schedule::StateData, prelude::*};
+
+/// Ties an entity to a state, it is despawned once that state is no longer
+/// current or paused on the stack.
+#[derive(Component)]
+pub struct StateScoped<S: StateData>(pub S);
+
+pub fn despawn_scoped<S: StateData>(
+    mut commands: Commands,
+    state: Res<State<S>>,
+    entities: Query<(Entity, &StateScoped<S>)>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    for (entity, scoped) in entities.iter() {
+        let active = state.current() == &scoped.0 || state.inactives().contains(&scoped.0);
+
+        if !active {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -3,5 +3,7 @@ pub enum State {
     Startup,
     Loading,
     Title,
+    LevelSelect,
     Play,
+    Results,
 }
@@ -2,16 +2,31 @@ use bevy::prelude::*;
 use bevy_kira_audio::AudioPlugin;
 
 use egg_fetcher::{
-    config, game,
+    config::{self, Args, ArgsError},
+    game,
     resources::{self, prelude::*},
     scenes,
 };
 
 fn main() {
+    let args = match Args::from_env() {
+        Ok(args) => args,
+        Err(ArgsError::Help) => {
+            println!("{}", ArgsError::Help);
+            return;
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            std::process::exit(2);
+        }
+    };
+
     App::new()
+        .insert_resource(args)
         .add_plugin(config::Plugin)
         .add_plugins(DefaultPlugins)
         .add_plugin(AudioPlugin)
+        .add_plugin(game::Plugin)
         .add_plugin(resources::Plugin)
         .add_plugin(scenes::Plugin)
         .insert_resource(ClearColor(Colors::DARK))
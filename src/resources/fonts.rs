@@ -1,4 +1,4 @@
-use bevy::{asset::LoadState, prelude::*};
+use bevy::{asset::HandleId, prelude::*};
 
 pub struct Fonts {
     pub dogica: Handle<Font>,
@@ -15,7 +15,7 @@ impl Fonts {
     }
 
     #[must_use]
-    pub fn all_loaded(&self, asset_server: &Res<AssetServer>) -> bool {
-        asset_server.get_group_load_state([self.fredoka.id, self.dogica.id]) == LoadState::Loaded
+    pub fn handle_ids(&self) -> Vec<HandleId> {
+        vec![self.fredoka.id, self.dogica.id]
     }
 }
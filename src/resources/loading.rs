@@ -0,0 +1,59 @@
+use bevy::{
+    asset::{HandleId, LoadState},
+    prelude::*,
+};
+
+#[derive(Default)]
+pub struct LoadingProgress {
+    pub loaded: usize,
+    pub total: usize,
+    pub failed: Vec<String>,
+}
+
+impl LoadingProgress {
+    #[must_use]
+    pub fn new(asset_server: &AssetServer, handle_ids: &[HandleId]) -> LoadingProgress {
+        let mut progress = LoadingProgress {
+            total: handle_ids.len(),
+            ..LoadingProgress::default()
+        };
+
+        for id in handle_ids {
+            match asset_server.get_load_state(*id) {
+                LoadState::Loaded => progress.loaded += 1,
+                LoadState::Failed => {
+                    let path = asset_server.get_handle_path(*id).map_or_else(
+                        || format!("{id:?}"),
+                        |path| path.path().display().to_string(),
+                    );
+                    progress.failed.push(path);
+                }
+                _ => {}
+            }
+        }
+
+        progress
+    }
+
+    #[must_use]
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = self.loaded as f32 / self.total as f32;
+
+        fraction
+    }
+
+    #[must_use]
+    pub fn has_failed(&self) -> bool {
+        !self.failed.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_done(&self) -> bool {
+        self.loaded == self.total
+    }
+}
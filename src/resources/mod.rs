@@ -1,17 +1,35 @@
 mod colors;
 mod fonts;
+mod loading;
 pub mod prelude;
+mod rng;
+mod sprites;
+mod tilesets;
 
-use crate::game;
+use crate::{
+    config::Args,
+    game::{self, SaveData},
+};
 use bevy::prelude::{Plugin as BevyPlugin, *};
 use prelude::*;
+use tilesets::{TileNames, TilesetLoader};
 
 pub struct Plugin;
 
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(game::State::Startup).with_system(startup))
-            .add_system_set(SystemSet::on_update(game::State::Loading).with_system(check_loading));
+        let seed = app.world.get_resource::<Args>().and_then(|args| args.seed);
+
+        app.insert_resource(GameRng::new(seed))
+            .init_resource::<LoadingProgress>()
+            .add_asset::<TileNames>()
+            .init_asset_loader::<TilesetLoader>()
+            .add_system_set(SystemSet::on_enter(game::State::Startup).with_system(startup))
+            .add_system_set(
+                SystemSet::on_update(game::State::Loading)
+                    .with_system(tilesets::resolve_tilesets.before(check_loading))
+                    .with_system(check_loading),
+            );
     }
 }
 
@@ -21,7 +39,12 @@ fn startup(
     asset_server: Res<AssetServer>,
 ) {
     let fonts = Fonts::load(&asset_server);
+    let sprites = Sprites::load(&asset_server);
+    let tilesets = Tilesets::load(&asset_server);
     commands.insert_resource(fonts);
+    commands.insert_resource(sprites);
+    commands.insert_resource(tilesets);
+    commands.insert_resource(SaveData::load());
 
     state.set(game::State::Loading).unwrap();
 }
@@ -29,11 +52,22 @@ fn startup(
 fn check_loading(
     mut state: ResMut<State<game::State>>,
     asset_server: Res<AssetServer>,
+    mut progress: ResMut<LoadingProgress>,
     fonts: Res<Fonts>,
+    sprites: Res<Sprites>,
+    tilesets: Res<Tilesets>,
+    args: Res<Args>,
 ) {
-    let all_loaded = fonts.all_loaded(&asset_server);
+    let mut handle_ids = fonts.handle_ids();
+    handle_ids.extend(sprites.handle_ids());
+    handle_ids.extend(tilesets.handle_ids());
+    *progress = LoadingProgress::new(&asset_server, &handle_ids);
 
-    if all_loaded {
-        state.set(game::State::Title).unwrap();
+    if progress.is_done() {
+        if args.skip_title {
+            state.set(game::State::Play).unwrap();
+        } else {
+            state.set(game::State::Title).unwrap();
+        }
     }
 }
@@ -1,2 +1,6 @@
 pub use super::colors::Colors;
 pub use super::fonts::Fonts;
+pub use super::loading::LoadingProgress;
+pub use super::rng::GameRng;
+pub use super::sprites::{CharacterSprites, Sprites};
+pub use super::tilesets::{Tileset, Tilesets};
@@ -0,0 +1,13 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+pub struct GameRng(pub StdRng);
+
+impl GameRng {
+    #[must_use]
+    pub fn new(seed: Option<u64>) -> GameRng {
+        match seed {
+            Some(seed) => GameRng(StdRng::seed_from_u64(seed)),
+            None => GameRng(StdRng::from_entropy()),
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use bevy::{asset::HandleId, prelude::*};
+
+pub struct CharacterSprites {
+    pub up: Handle<Image>,
+    pub down: Handle<Image>,
+    pub left: Handle<Image>,
+    pub right: Handle<Image>,
+    pub dead: Handle<Image>,
+}
+
+impl CharacterSprites {
+    fn load(asset_server: &Res<AssetServer>, name: &str) -> CharacterSprites {
+        CharacterSprites {
+            up: asset_server.load(&format!("sprites/Characters/{name}_Up.png")),
+            down: asset_server.load(&format!("sprites/Characters/{name}_Down.png")),
+            left: asset_server.load(&format!("sprites/Characters/{name}_Left.png")),
+            right: asset_server.load(&format!("sprites/Characters/{name}_Right.png")),
+            dead: asset_server.load(&format!("sprites/Characters/{name}_Dead.png")),
+        }
+    }
+
+    fn handle_ids(&self) -> [HandleId; 5] {
+        [
+            self.up.id,
+            self.down.id,
+            self.left.id,
+            self.right.id,
+            self.dead.id,
+        ]
+    }
+}
+
+/// Fill images for progress bars.
+pub struct BarSprites {
+    pub green: Handle<Image>,
+    pub blue: Handle<Image>,
+    pub red: Handle<Image>,
+}
+
+impl BarSprites {
+    fn load(asset_server: &Res<AssetServer>) -> BarSprites {
+        BarSprites {
+            green: asset_server.load("sprites/UI/Bar_Green.png"),
+            blue: asset_server.load("sprites/UI/Bar_Blue.png"),
+            red: asset_server.load("sprites/UI/Bar_Red.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> [HandleId; 3] {
+        [self.green.id, self.blue.id, self.red.id]
+    }
+}
+
+/// Ground arrows, one per direction.
+pub struct ArrowSprites {
+    pub up: Handle<Image>,
+    pub down: Handle<Image>,
+    pub left: Handle<Image>,
+    pub right: Handle<Image>,
+}
+
+impl ArrowSprites {
+    fn load(asset_server: &Res<AssetServer>) -> ArrowSprites {
+        ArrowSprites {
+            up: asset_server.load("sprites/Terrain_Common/Arrow_Up.png"),
+            down: asset_server.load("sprites/Terrain_Common/Arrow_Down.png"),
+            left: asset_server.load("sprites/Terrain_Common/Arrow_Left.png"),
+            right: asset_server.load("sprites/Terrain_Common/Arrow_Right.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> [HandleId; 4] {
+        [self.up.id, self.down.id, self.left.id, self.right.id]
+    }
+}
+
+/// Tiles with gameplay effects, goo and ice come in a few variations.
+pub struct HazardSprites {
+    pub spikes: Handle<Image>,
+    pub spikes_active: Handle<Image>,
+    pub goo: [Handle<Image>; 4],
+    pub ice: [Handle<Image>; 3],
+    pub water: Handle<Image>,
+    pub bridge: Handle<Image>,
+    pub water_sign: Handle<Image>,
+}
+
+impl HazardSprites {
+    fn load(asset_server: &Res<AssetServer>) -> HazardSprites {
+        HazardSprites {
+            spikes: asset_server.load("sprites/Objects/Spikes.png"),
+            spikes_active: asset_server.load("sprites/Objects/Spikes_Active.png"),
+            goo: [1, 2, 3, 4].map(|n| asset_server.load(&format!("sprites/Objects/Goo_0{n}.png"))),
+            ice: [1, 2, 3].map(|n| asset_server.load(&format!("sprites/Objects/Ice_0{n}.png"))),
+            water: asset_server.load("sprites/Terrain_Common/Water.png"),
+            bridge: asset_server.load("sprites/Terrain_Common/Bridge.png"),
+            water_sign: asset_server.load("sprites/Objects/Danger_Water.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> Vec<HandleId> {
+        let mut ids = vec![
+            self.spikes.id,
+            self.spikes_active.id,
+            self.water.id,
+            self.bridge.id,
+            self.water_sign.id,
+        ];
+        ids.extend(self.goo.iter().map(|handle| handle.id));
+        ids.extend(self.ice.iter().map(|handle| handle.id));
+
+        ids
+    }
+}
+
+/// Keys and the doors they open.
+pub struct KeySprites {
+    pub golden_key: Handle<Image>,
+    pub silver_key: Handle<Image>,
+    pub golden_door: Handle<Image>,
+    pub silver_door: Handle<Image>,
+}
+
+impl KeySprites {
+    fn load(asset_server: &Res<AssetServer>) -> KeySprites {
+        KeySprites {
+            golden_key: asset_server.load("sprites/Objects/Key_Golden.png"),
+            silver_key: asset_server.load("sprites/Objects/Key_Silver.png"),
+            // The golden door image has a silver frame, the golden one is the second.
+            golden_door: asset_server.load("sprites/Objects/Door_Golden(2).png"),
+            silver_door: asset_server.load("sprites/Objects/Door_Golden.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> [HandleId; 4] {
+        [
+            self.golden_key.id,
+            self.silver_key.id,
+            self.golden_door.id,
+            self.silver_door.id,
+        ]
+    }
+}
+
+/// Garden beds and the tools to tend them. Crops are listed as beetroot,
+/// carrots, cucumbers, onions, radish and tomatoes.
+pub struct GardenSprites {
+    pub blank: Handle<Image>,
+    /// Growing and ripe bed of every crop.
+    pub beds: [[Handle<Image>; 2]; 6],
+    /// Harvested crops.
+    pub produce: [Handle<Image>; 6],
+    pub shovel: Handle<Image>,
+    pub watering_can: Handle<Image>,
+}
+
+impl GardenSprites {
+    fn load(asset_server: &Res<AssetServer>) -> GardenSprites {
+        let beds = [
+            "Beetroot",
+            "Carrots",
+            "Cucumbers",
+            "Onions",
+            "Radish",
+            "Tomatoes",
+        ];
+        let produce = [
+            "Beetroot", "Carrot", "Cucumber", "Onion", "Radish", "Tomato",
+        ];
+
+        GardenSprites {
+            blank: asset_server.load("sprites/Objects/GardenBed_Blank.png"),
+            beds: beds.map(|name| {
+                [1, 2].map(|stage| {
+                    asset_server.load(&format!("sprites/Objects/GardenBed_{name}_0{stage}.png"))
+                })
+            }),
+            produce: produce.map(|name| asset_server.load(&format!("sprites/Objects/{name}.png"))),
+            shovel: asset_server.load("sprites/Objects/Showel.png"),
+            watering_can: asset_server.load("sprites/Objects/WateringCan.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> Vec<HandleId> {
+        let mut ids = vec![self.blank.id, self.shovel.id, self.watering_can.id];
+        ids.extend(self.beds.iter().flatten().map(|handle| handle.id));
+        ids.extend(self.produce.iter().map(|handle| handle.id));
+
+        ids
+    }
+}
+
+/// Speech bubbles shown above characters.
+pub struct StatusSprites {
+    pub angry: Handle<Image>,
+    pub exclamation: Handle<Image>,
+    pub happy: Handle<Image>,
+    pub love: Handle<Image>,
+    pub starving: Handle<Image>,
+    pub thirsty: Handle<Image>,
+}
+
+impl StatusSprites {
+    fn load(asset_server: &Res<AssetServer>) -> StatusSprites {
+        StatusSprites {
+            angry: asset_server.load("sprites/UI/Status_Angry.png"),
+            exclamation: asset_server.load("sprites/UI/Status_Exclamation.png"),
+            happy: asset_server.load("sprites/UI/Status_Happy.png"),
+            love: asset_server.load("sprites/UI/Status_Love.png"),
+            starving: asset_server.load("sprites/UI/Status_Starving.png"),
+            thirsty: asset_server.load("sprites/UI/Status_Thirsty.png"),
+        }
+    }
+
+    fn handle_ids(&self) -> [HandleId; 6] {
+        [
+            self.angry.id,
+            self.exclamation.id,
+            self.happy.id,
+            self.love.id,
+            self.starving.id,
+            self.thirsty.id,
+        ]
+    }
+}
+
+pub struct Sprites {
+    pub cat: CharacterSprites,
+    pub chick: CharacterSprites,
+    pub fox: CharacterSprites,
+    pub mouse: CharacterSprites,
+    pub egg: Handle<Image>,
+    pub crystal: Handle<Image>,
+    pub ruby: Handle<Image>,
+    pub hole: Handle<Image>,
+    pub coop: Handle<Image>,
+    pub fountain: Handle<Image>,
+    pub feed: Handle<Image>,
+    pub chick_avatar: Handle<Image>,
+    pub bars: BarSprites,
+    pub arrows: ArrowSprites,
+    pub hazards: HazardSprites,
+    pub keys: KeySprites,
+    pub status: StatusSprites,
+    pub garden: GardenSprites,
+    pub toolbar_top: Handle<Image>,
+    pub toolbar_bottom: Handle<Image>,
+}
+
+impl Sprites {
+    #[must_use]
+    pub fn load(asset_server: &Res<AssetServer>) -> Sprites {
+        Sprites {
+            cat: CharacterSprites::load(asset_server, "Cat"),
+            chick: CharacterSprites::load(asset_server, "Chick"),
+            fox: CharacterSprites::load(asset_server, "Fox"),
+            mouse: CharacterSprites::load(asset_server, "Mouse"),
+            egg: asset_server.load("sprites/Objects/Coin.png"),
+            crystal: asset_server.load("sprites/Objects/Crystal.png"),
+            ruby: asset_server.load("sprites/Objects/Ruby.png"),
+            hole: asset_server.load("sprites/Objects/Hole.png"),
+            coop: asset_server.load("sprites/Objects/Box.png"),
+            fountain: asset_server.load("sprites/Objects/Fountain.png"),
+            feed: asset_server.load("sprites/Objects/Carrot.png"),
+            chick_avatar: asset_server.load("sprites/Characters/Chick_Avatar_Circle.png"),
+            bars: BarSprites::load(asset_server),
+            arrows: ArrowSprites::load(asset_server),
+            hazards: HazardSprites::load(asset_server),
+            keys: KeySprites::load(asset_server),
+            status: StatusSprites::load(asset_server),
+            garden: GardenSprites::load(asset_server),
+            toolbar_top: asset_server.load("sprites/UI/UI_Toolbar_Top.png"),
+            toolbar_bottom: asset_server.load("sprites/UI/UI_Toolbar_Bottom.png"),
+        }
+    }
+
+    #[must_use]
+    pub fn handle_ids(&self) -> Vec<HandleId> {
+        let mut ids = vec![
+            self.egg.id,
+            self.crystal.id,
+            self.ruby.id,
+            self.hole.id,
+            self.coop.id,
+            self.fountain.id,
+            self.feed.id,
+            self.chick_avatar.id,
+            self.toolbar_top.id,
+            self.toolbar_bottom.id,
+        ];
+
+        ids.extend(self.bars.handle_ids());
+        ids.extend(self.arrows.handle_ids());
+        ids.extend(self.hazards.handle_ids());
+        ids.extend(self.keys.handle_ids());
+        ids.extend(self.status.handle_ids());
+        ids.extend(self.garden.handle_ids());
+
+        for characters in [&self.cat, &self.chick, &self.fox, &self.mouse] {
+            ids.extend(characters.handle_ids());
+        }
+
+        ids
+    }
+}
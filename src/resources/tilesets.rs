@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use bevy::{
+    asset::{AssetLoader, BoxedFuture, HandleId, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    sprite::Rect as AtlasRect,
+};
+
+const FENCES: &str = "tilesets/fences.tileset";
+const GROUND: &str = "tilesets/ground.tileset";
+
+/// Layout of a tileset image and the names of its tiles, parsed from a
+/// `.tileset` description file.
+struct Description<'a> {
+    image: &'a str,
+    tile_size: Vec2,
+    offset: Vec2,
+    columns: usize,
+    rows: usize,
+    tiles: HashMap<String, usize>,
+}
+
+impl<'a> Description<'a> {
+    fn parse(source: &'a str) -> Result<Description<'a>, String> {
+        let mut image = None;
+        let mut tile_size = None;
+        let mut offset = Vec2::ZERO;
+        let mut columns = None;
+        let mut rows = None;
+        let mut tiles = HashMap::new();
+
+        for line in source.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            match (words.next(), words.collect::<Vec<_>>().as_slice()) {
+                (Some("image"), [path]) => image = Some(*path),
+                (Some("tile_size"), [width, height]) => tile_size = Some(vec2(width, height)?),
+                (Some("offset"), [x, y]) => offset = vec2(x, y)?,
+                (Some("columns"), [value]) => columns = Some(number(value)?),
+                (Some("rows"), [value]) => rows = Some(number(value)?),
+                (Some("tile"), [name, index]) => {
+                    tiles.insert((*name).to_string(), number(index)?);
+                }
+                _ => return Err(format!("unexpected line `{line}`")),
+            }
+        }
+
+        Ok(Description {
+            image: image.ok_or("missing `image`")?,
+            tile_size: tile_size.ok_or("missing `tile_size`")?,
+            offset,
+            columns: columns.ok_or("missing `columns`")?,
+            rows: rows.ok_or("missing `rows`")?,
+            tiles,
+        })
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn atlas(&self, texture: Handle<Image>) -> TextureAtlas {
+        let grid = Vec2::new(self.columns as f32, self.rows as f32);
+        let mut atlas = TextureAtlas::new_empty(texture, self.offset + grid * self.tile_size);
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let min = self.offset + Vec2::new(column as f32, row as f32) * self.tile_size;
+
+                atlas.add_texture(AtlasRect {
+                    min,
+                    max: min + self.tile_size,
+                });
+            }
+        }
+
+        atlas
+    }
+}
+
+fn number(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("`{value}` is not a number"))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn vec2(x: &str, y: &str) -> Result<Vec2, String> {
+    Ok(Vec2::new(number(x)? as f32, number(y)? as f32))
+}
+
+/// Tile names of a loaded `.tileset` file, its atlas is the `atlas` labeled
+/// asset of the same file.
+#[derive(TypeUuid)]
+#[uuid = "6f0b7d0e-3c1a-4f55-9b2e-8d4a0c6e1f27"]
+pub struct TileNames {
+    texture: Handle<Image>,
+    tiles: HashMap<String, usize>,
+}
+
+/// Loads `.tileset` files, a broken one fails to load like any other asset
+/// and shows up on the loading screen.
+#[derive(Default)]
+pub struct TilesetLoader;
+
+impl AssetLoader for TilesetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
+        Box::pin(async move {
+            let description =
+                Description::parse(std::str::from_utf8(bytes)?).map_err(anyhow::Error::msg)?;
+            let texture = load_context.get_handle(description.image);
+
+            load_context.set_labeled_asset(
+                "atlas",
+                LoadedAsset::new(description.atlas(texture.clone())),
+            );
+            load_context.set_default_asset(
+                LoadedAsset::new(TileNames {
+                    texture,
+                    tiles: description.tiles,
+                })
+                .with_dependency(description.image.into()),
+            );
+
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tileset"]
+    }
+}
+
+/// Atlas and tile names are only known once the description file is loaded,
+/// see [`resolve_tilesets`].
+pub struct Tileset {
+    pub atlas: Handle<TextureAtlas>,
+    names: Handle<TileNames>,
+    texture: Handle<Image>,
+    tiles: HashMap<String, usize>,
+}
+
+impl Tileset {
+    fn load(path: &str, asset_server: &Res<AssetServer>) -> Tileset {
+        Tileset {
+            atlas: asset_server.load(&format!("{path}#atlas")),
+            names: asset_server.load(path),
+            texture: Handle::default(),
+            tiles: HashMap::new(),
+        }
+    }
+
+    /// Atlas index of the tile with the given name.
+    ///
+    /// # Panics
+    ///
+    /// When the tileset description does not name such a tile.
+    #[must_use]
+    pub fn index(&self, name: &str) -> usize {
+        self.tiles[name]
+    }
+
+    #[must_use]
+    pub fn sprite(&self, name: &str) -> TextureAtlasSprite {
+        TextureAtlasSprite::new(self.index(name))
+    }
+
+    fn resolve(&mut self, names: &Assets<TileNames>) {
+        if !self.tiles.is_empty() {
+            return;
+        }
+        if let Some(names) = names.get(&self.names) {
+            self.texture = names.texture.clone();
+            self.tiles = names.tiles.clone();
+        }
+    }
+}
+
+pub struct Tilesets {
+    pub fences: Tileset,
+    pub ground: Tileset,
+}
+
+impl Tilesets {
+    #[must_use]
+    pub fn load(asset_server: &Res<AssetServer>) -> Tilesets {
+        Tilesets {
+            fences: Tileset::load(FENCES, asset_server),
+            ground: Tileset::load(GROUND, asset_server),
+        }
+    }
+
+    /// The image of a tileset counts as not loaded until its description is.
+    #[must_use]
+    pub fn handle_ids(&self) -> Vec<HandleId> {
+        [&self.fences, &self.ground]
+            .into_iter()
+            .flat_map(|tileset| [tileset.names.id, tileset.texture.id])
+            .collect()
+    }
+}
+
+/// Copies the tile names over from loaded description files.
+pub fn resolve_tilesets(mut tilesets: ResMut<Tilesets>, names: Res<Assets<TileNames>>) {
+    tilesets.fences.resolve(&names);
+    tilesets.ground.resolve(&names);
+}
@@ -0,0 +1,66 @@
+mod ui;
+
+use bevy::prelude::{Input, Plugin as BevyPlugin, *};
+
+use crate::{
+    game::{self, SaveData, SelectedLevel},
+    resources::prelude::*,
+    ui::{ActionKind, ActionMarker},
+};
+
+use super::play;
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(game::State::LevelSelect).with_system(setup))
+            .add_system_set(
+                SystemSet::on_update(game::State::LevelSelect).with_system(buttons_interactions),
+            );
+    }
+}
+
+fn setup(mut commands: Commands, fonts: Res<Fonts>, save: Res<SaveData>) {
+    let levels: Vec<usize> = play::levels().collect();
+    ui::spawn(&mut commands, &fonts, &save, &levels);
+}
+
+fn buttons_interactions(
+    mut mouse_button_input: ResMut<Input<MouseButton>>,
+    mut query: Query<
+        (&ActionMarker, &Interaction, &mut UiColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut selected: ResMut<SelectedLevel>,
+    mut app_state: ResMut<State<game::State>>,
+) {
+    for (action, interaction, mut color) in query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                // workaround for input persistence between states
+                // see: https://github.com/bevyengine/bevy/issues/1700#issuecomment-886999222
+                mouse_button_input.reset(MouseButton::Left);
+
+                match action.kind() {
+                    ActionKind::Level(level) => {
+                        selected.0 = *level;
+                        app_state.set(game::State::Play).unwrap();
+                    }
+                    ActionKind::Title => {
+                        app_state.set(game::State::Title).unwrap();
+                    }
+                    ActionKind::Play | ActionKind::Retry | ActionKind::Quit => {}
+                }
+
+                *color = Colors::DARK.into();
+            }
+            Interaction::Hovered => {
+                *color = Colors::LIGHT.into();
+            }
+            Interaction::None => {
+                *color = Colors::PRIMARY.into();
+            }
+        }
+    }
+}
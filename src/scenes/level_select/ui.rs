@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{self, SaveData, StateScoped},
+    resources::prelude::*,
+    ui::{Action, ActionMarker, EmbossedText, Housing, Overlay, SimpleText},
+};
+
+fn spawn_camera(commands: &mut Commands) {
+    commands
+        .spawn_bundle(UiCameraBundle::default())
+        .insert(StateScoped(game::State::LevelSelect));
+}
+
+pub fn spawn(commands: &mut Commands, fonts: &Fonts, save: &SaveData, levels: &[usize]) {
+    let font = &fonts.fredoka;
+    let button_size = Size::new(Val::Px(400.0), Val::Px(50.0));
+
+    let overlay = Overlay::new();
+    let top = Housing::percent(100.0, 30.0);
+    let mut list = Housing::percent(100.0, 50.0);
+    let mut actions = Housing::percent(100.0, 20.0);
+
+    let title = EmbossedText::big("Select level", font);
+    let mut totals = SimpleText::small(save.totals_text(), font);
+    let title_action = Action::new("Title", font, button_size);
+
+    totals.color(Colors::PRIMARY);
+    list.justify_content(JustifyContent::FlexStart)
+        .align_items(AlignItems::Center);
+    actions
+        .justify_content(JustifyContent::Center)
+        .align_items(AlignItems::Center);
+
+    overlay.spawn(
+        commands,
+        |parent| {
+            top.spawn(parent, |parent| {
+                title.spawn(parent);
+                totals.spawn(parent);
+            });
+            list.spawn(parent, |parent| {
+                for &level in levels {
+                    let action = Action::new(format!("Level {level}"), font, button_size);
+                    let mut record = SimpleText::small(save.record_text(level), font);
+                    record.color(Colors::PRIMARY);
+
+                    action.spawn(parent, ActionMarker::level(level));
+                    record.spawn(parent);
+                }
+            });
+            actions.spawn(parent, |parent| {
+                title_action.spawn(parent, ActionMarker::title());
+            });
+        },
+        StateScoped(game::State::LevelSelect),
+    );
+
+    spawn_camera(commands);
+}
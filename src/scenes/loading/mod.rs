@@ -1,23 +1,92 @@
 mod ui;
 
-use crate::{game, resources::prelude::*};
-use bevy::prelude::{Plugin as BevyPlugin, *};
+use crate::{
+    game,
+    resources::prelude::*,
+    ui::{ActionKind, ActionMarker, Progress},
+};
+use bevy::prelude::{Input, Plugin as BevyPlugin, *};
 
 pub struct Plugin;
 
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut App) {
         app.add_system_set(SystemSet::on_enter(game::State::Loading).with_system(setup))
-            .add_system_set(SystemSet::on_exit(game::State::Loading).with_system(cleanup));
+            .add_system_set(
+                SystemSet::on_update(game::State::Loading)
+                    .with_system(update_progress)
+                    .with_system(show_failures)
+                    .with_system(buttons_interactions),
+            );
     }
 }
 
-fn setup(mut commands: Commands, fonts: Res<Fonts>) {
-    ui::spawn(&mut commands, &fonts);
+fn setup(mut commands: Commands, fonts: Res<Fonts>, sprites: Res<Sprites>) {
+    ui::spawn(&mut commands, &fonts, &sprites);
 }
 
-fn cleanup(mut commands: Commands, entities: Query<Entity, With<ui::ScopedMarker>>) {
-    for entity in entities.iter() {
-        commands.entity(entity).despawn_recursive();
+fn update_progress(
+    progress: Res<LoadingProgress>,
+    mut bars: Query<&mut Style, With<ui::ProgressMarker>>,
+) {
+    for mut style in bars.iter_mut() {
+        Progress::set(&mut style, progress.fraction());
+    }
+}
+
+fn show_failures(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    progress: Res<LoadingProgress>,
+    panels: Query<(), With<ui::FailureMarker>>,
+) {
+    if progress.has_failed() && panels.is_empty() {
+        for path in &progress.failed {
+            error!("Failed to load asset {}", path);
+        }
+
+        ui::spawn_failures(&mut commands, &fonts, &progress.failed);
+    }
+}
+
+fn buttons_interactions(
+    mut commands: Commands,
+    mut mouse_button_input: ResMut<Input<MouseButton>>,
+    mut query: Query<
+        (&ActionMarker, &Interaction, &mut UiColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut progress: ResMut<LoadingProgress>,
+    asset_server: Res<AssetServer>,
+    panels: Query<Entity, With<ui::FailureMarker>>,
+) {
+    for (action, interaction, mut color) in query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                // workaround for input persistence between states
+                // see: https://github.com/bevyengine/bevy/issues/1700#issuecomment-886999222
+                mouse_button_input.reset(MouseButton::Left);
+
+                if let ActionKind::Retry = action.kind() {
+                    // Failed assets are loaded again, the handles held by the
+                    // collections keep them alive.
+                    for path in progress.failed.drain(..) {
+                        let _ = asset_server.load_untyped(path.as_str());
+                    }
+
+                    for entity in panels.iter() {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+
+                *color = Colors::DARK.into();
+            }
+            Interaction::Hovered => {
+                *color = Colors::LIGHT.into();
+            }
+            Interaction::None => {
+                *color = Colors::PRIMARY.into();
+            }
+        }
     }
 }
@@ -1,23 +1,31 @@
 use bevy::prelude::*;
 
 use crate::{
+    game::{self, StateScoped},
     resources::prelude::*,
-    ui::{Overlay, SimpleText},
+    ui::{Action, ActionMarker, EmbossedText, Housing, Overlay, Progress, SimpleText},
 };
 
 #[derive(Component)]
-pub struct ScopedMarker;
+pub struct ProgressMarker;
+
+#[derive(Component)]
+pub struct FailureMarker;
 
 fn spawn_camera(commands: &mut Commands) {
     commands
         .spawn_bundle(UiCameraBundle::default())
-        .insert(ScopedMarker);
+        .insert(StateScoped(game::State::Loading));
 }
 
-pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
+pub fn spawn(commands: &mut Commands, fonts: &Fonts, sprites: &Sprites) {
     let font = &fonts.fredoka;
     let overlay = Overlay::new();
     let mut loading_text = SimpleText::big("Loading...", font);
+    let progress = Progress::new(
+        &sprites.bars.green,
+        Size::new(Val::Px(400.0), Val::Px(24.0)),
+    );
 
     loading_text.color(Colors::PRIMARY);
 
@@ -25,9 +33,49 @@ pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
         commands,
         |parent| {
             loading_text.spawn(parent);
+            progress.spawn(parent, ProgressMarker);
         },
-        ScopedMarker,
+        StateScoped(game::State::Loading),
     );
 
     spawn_camera(commands);
 }
+
+pub fn spawn_failures(commands: &mut Commands, fonts: &Fonts, failed: &[String]) {
+    let font = &fonts.fredoka;
+    let button_size = Size::new(Val::Px(400.0), Val::Px(50.0));
+
+    let mut overlay = Overlay::new();
+    let top = Housing::percent(100.0, 30.0);
+    let paths = Housing::percent(100.0, 50.0);
+    let actions = Housing::percent(100.0, 20.0);
+
+    let title = EmbossedText::big("Failed to load assets", font);
+    let retry = Action::new("Retry", font, button_size);
+
+    overlay.color(Colors::DARK);
+
+    let entity = overlay.spawn(
+        commands,
+        |parent| {
+            top.spawn(parent, |parent| {
+                title.spawn(parent);
+            });
+            paths.spawn(parent, |parent| {
+                for path in failed {
+                    let mut text = SimpleText::small(path, font);
+                    text.color(Colors::PRIMARY);
+                    text.spawn(parent);
+                }
+            });
+            actions.spawn(parent, |parent| {
+                retry.spawn(parent, ActionMarker::retry());
+            });
+        },
+        FailureMarker,
+    );
+
+    commands
+        .entity(entity)
+        .insert(StateScoped(game::State::Loading));
+}
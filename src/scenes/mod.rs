@@ -1,13 +1,21 @@
+mod level_select;
 mod loading;
-mod title;
 mod play;
+mod results;
+mod title;
 
 use bevy::prelude::{Plugin as BevyPlugin, *};
 
+pub use play::levels;
+
 pub struct Plugin;
 
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(loading::Plugin).add_plugin(title::Plugin).add_plugin(play::Plugin);
+        app.add_plugin(loading::Plugin)
+            .add_plugin(title::Plugin)
+            .add_plugin(level_select::Plugin)
+            .add_plugin(play::Plugin)
+            .add_plugin(results::Plugin);
     }
 }
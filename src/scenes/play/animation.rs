@@ -0,0 +1,189 @@
+use bevy::{prelude::*, sprite::Anchor};
+
+use crate::resources::prelude::{CharacterSprites, Sprites};
+
+const WALK_CYCLES_PER_SECOND: f32 = 3.;
+const SQUASH: f32 = 0.06;
+const BOB: f32 = 0.04;
+const MIN_MOVEMENT: f32 = 0.01;
+
+#[derive(Component, Clone, Copy)]
+pub enum Character {
+    Cat,
+    Chick,
+    Fox,
+    Mouse,
+}
+
+impl Character {
+    fn sprites(self, sprites: &Sprites) -> &CharacterSprites {
+        match self {
+            Character::Cat => &sprites.cat,
+            Character::Chick => &sprites.chick,
+            Character::Fox => &sprites.fox,
+            Character::Mouse => &sprites.mouse,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum Facing {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Facing {
+    fn from_direction(direction: Vec2) -> Facing {
+        if direction.x.abs() > direction.y.abs() {
+            if direction.x > 0. {
+                Facing::Right
+            } else {
+                Facing::Left
+            }
+        } else if direction.y > 0. {
+            Facing::Up
+        } else {
+            Facing::Down
+        }
+    }
+}
+
+/// Tracks how a character moved since the last frame to drive its walk cycle.
+/// The walk squashes the sprite relative to `scale`, growing characters
+/// change that instead of their transform.
+#[derive(Component)]
+pub struct Walk {
+    last_position: Vec2,
+    moving: bool,
+    cycle: f32,
+    pub scale: f32,
+}
+
+impl Walk {
+    pub fn new(position: Vec3) -> Walk {
+        Walk {
+            last_position: position.truncate(),
+            moving: false,
+            cycle: 0.,
+            scale: 1.,
+        }
+    }
+
+    #[must_use]
+    pub fn with_scale(mut self, scale: f32) -> Walk {
+        self.scale = scale;
+        self
+    }
+}
+
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateFacing;
+
+pub fn update_facing(mut characters: Query<(&Transform, &mut Walk, &mut Facing)>) {
+    for (transform, mut walk, mut facing) in characters.iter_mut() {
+        let position = transform.translation.truncate();
+        let movement = position - walk.last_position;
+
+        walk.last_position = position;
+        walk.moving = movement.length() > MIN_MOVEMENT;
+
+        if walk.moving {
+            let next = Facing::from_direction(movement);
+            if *facing != next {
+                *facing = next;
+            }
+        }
+    }
+}
+
+pub fn select_sprite(
+    sprites: Res<Sprites>,
+    mut characters: Query<(&Character, &Facing, &mut Handle<Image>), Changed<Facing>>,
+) {
+    for (character, facing, mut texture) in characters.iter_mut() {
+        let set = character.sprites(&sprites);
+
+        *texture = match facing {
+            Facing::Up => set.up.clone(),
+            Facing::Down => set.down.clone(),
+            Facing::Left => set.left.clone(),
+            Facing::Right => set.right.clone(),
+        };
+    }
+}
+
+pub fn animate_walk(
+    time: Res<Time>,
+    mut characters: Query<(&mut Walk, &mut Transform, &mut Sprite)>,
+) {
+    for (mut walk, mut transform, mut sprite) in characters.iter_mut() {
+        walk.cycle = if walk.moving {
+            (walk.cycle + time.delta_seconds() * WALK_CYCLES_PER_SECOND).fract()
+        } else {
+            0.
+        };
+
+        let wave = (walk.cycle * std::f32::consts::TAU).sin();
+        let scale = Vec3::new(
+            walk.scale * (1. - wave * SQUASH),
+            walk.scale * (1. + wave * SQUASH),
+            1.,
+        );
+        let anchor = Vec2::new(0., -wave.abs() * BOB);
+
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+        if !matches!(sprite.anchor, Anchor::Custom(current) if current == anchor) {
+            sprite.anchor = Anchor::Custom(anchor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn walk_once(walk: Walk) -> Vec3 {
+        let mut world = World::new();
+        world.insert_resource(Time::default());
+        let entity = world
+            .spawn()
+            .insert(walk)
+            .insert(Transform::default())
+            .insert(Sprite::default())
+            .id();
+
+        let mut stage = SystemStage::single_threaded();
+        stage.add_system(animate_walk);
+        stage.run(&mut world);
+
+        world.get::<Transform>(entity).unwrap().scale
+    }
+
+    #[test]
+    fn walking_keeps_the_base_scale() {
+        let walk = Walk {
+            moving: true,
+            cycle: 0.25,
+            ..Walk::new(Vec3::ZERO).with_scale(0.5)
+        };
+
+        let scale = walk_once(walk);
+
+        assert!((scale.x - 0.5 * (1. - SQUASH)).abs() < 1e-5);
+        assert!((scale.y - 0.5 * (1. + SQUASH)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn standing_still_returns_to_the_base_scale() {
+        let walk = Walk {
+            cycle: 0.25,
+            ..Walk::new(Vec3::ZERO).with_scale(0.5)
+        };
+
+        assert_eq!(walk_once(walk), Vec3::new(0.5, 0.5, 1.));
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+
+use super::{eggs::EggKind, feedback::EggsBanked, CollectedCoins, DeliveredEggs, Player};
+
+/// How close the player has to get to a coop to empty the basket.
+pub const DELIVERY_DISTANCE: f32 = 80. * 80.;
+/// Share of the player speed lost when the basket is full.
+const FULL_BASKET_SLOWDOWN: f32 = 0.4;
+
+/// Eggs picked up by the player, they only score once delivered to a coop.
+pub struct Basket {
+    carried: Vec<EggKind>,
+    capacity: usize,
+}
+
+impl Basket {
+    pub fn new(capacity: usize) -> Basket {
+        Basket {
+            carried: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.carried.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.carried.len() >= self.capacity
+    }
+
+    /// Adds the egg unless the basket is full, returns whether it fit.
+    pub fn put(&mut self, kind: EggKind) -> bool {
+        if self.is_full() {
+            return false;
+        }
+
+        self.carried.push(kind);
+        true
+    }
+
+    /// Multiplier for the player speed, the fuller the basket the slower.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn speed_factor(&self) -> f32 {
+        let fill = self.carried.len() as f32 / self.capacity.max(1) as f32;
+        1. - FULL_BASKET_SLOWDOWN * fill
+    }
+}
+
+/// Drop-off tile where the basket is emptied.
+#[derive(Component)]
+pub struct Coop;
+
+pub fn deliver_eggs(
+    mut basket: ResMut<Basket>,
+    mut collected_coins: ResMut<CollectedCoins>,
+    mut delivered: ResMut<DeliveredEggs>,
+    mut banked: EventWriter<EggsBanked>,
+    player: Query<&Transform, With<Player>>,
+    coops: Query<&Transform, With<Coop>>,
+) {
+    if basket.carried.is_empty() {
+        return;
+    }
+
+    let player = player.single().translation.truncate();
+    let Some(coop) = coops
+        .iter()
+        .find(|coop| coop.translation.truncate().distance_squared(player) < DELIVERY_DISTANCE)
+    else {
+        return;
+    };
+
+    let eggs = basket.carried.len();
+    delivered.0 += basket
+        .carried
+        .iter()
+        .filter(|kind| kind.counts_for_goal())
+        .count();
+    let points = basket.carried.drain(..).map(EggKind::points).sum();
+    collected_coins.0 = collected_coins.0.saturating_add_signed(points);
+
+    banked.send(EggsBanked {
+        position: coop.translation,
+        points,
+        eggs,
+    });
+}
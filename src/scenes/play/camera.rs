@@ -0,0 +1,179 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    math::const_vec2,
+    prelude::*,
+};
+
+use super::{CurrentLevel, MainCamera, Player};
+use crate::config::Accessibility;
+
+/// How far the player can move from the camera center before it follows.
+const DEAD_ZONE: Vec2 = const_vec2!([96., 64.]);
+/// How quickly the camera closes the distance to its target, higher is snappier.
+const FOLLOW_SHARPNESS: f32 = 6.;
+
+const MIN_ZOOM: f32 = 0.5;
+const MAX_ZOOM: f32 = 2.;
+/// Scale factor applied per mouse wheel line.
+const ZOOM_STEP: f32 = 1.1;
+/// Wheel lines per second while a gamepad trigger is fully pressed.
+const TRIGGER_ZOOM_RATE: f32 = 8.;
+/// Pixel scrolling devices report roughly this many pixels per wheel line.
+const PIXELS_PER_LINE: f32 = 100.;
+const ZOOM_SHARPNESS: f32 = 10.;
+
+/// Trauma lost per second, so a full shake settles in one second.
+const TRAUMA_DECAY: f32 = 1.;
+const MAX_SHAKE_OFFSET: f32 = 24.;
+const MAX_SHAKE_ANGLE: f32 = 0.05;
+const SHAKE_FREQUENCY: f32 = 25.;
+
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CameraFollow;
+
+/// World space area covered by the current map.
+pub struct MapBounds {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+/// Projection scale the camera eases towards.
+#[derive(Component)]
+pub struct Zoom {
+    target: f32,
+}
+
+impl Default for Zoom {
+    fn default() -> Zoom {
+        Zoom { target: 1. }
+    }
+}
+
+/// Trauma based camera shake, the shake strength grows with the square of
+/// the trauma which decays over time.
+#[derive(Component, Default)]
+pub struct Shake {
+    trauma: f32,
+    offset: Vec2,
+}
+
+/// Adds trauma to the main camera, `trauma` goes from `0.` to `1.`.
+pub struct ShakeEvent {
+    pub trauma: f32,
+}
+
+/// Clamps one axis of the camera center so the viewport stays inside the map,
+/// or centers it when the map is smaller than the viewport.
+fn clamp_axis(center: f32, half_extent: f32, min: f32, max: f32) -> f32 {
+    if max - min <= half_extent * 2. {
+        min + (max - min) / 2.
+    } else {
+        center.clamp(min + half_extent, max - half_extent)
+    }
+}
+
+pub fn camera_follow_player(
+    bounds: Res<MapBounds>,
+    time: Res<Time>,
+    mut transforms: ParamSet<(
+        Query<&Transform, With<Player>>,
+        Query<(&mut Transform, &OrthographicProjection, &Shake), With<MainCamera>>,
+    )>,
+) {
+    let player_transform_query = transforms.p0();
+    let player_translation = player_transform_query.single().translation.truncate();
+
+    let mut camera_query = transforms.p1();
+    let (mut camera_transform, projection, shake) = camera_query.single_mut();
+    let camera_translation = camera_transform.translation.truncate() - shake.offset;
+
+    let offset = player_translation - camera_translation;
+    let outside_dead_zone = offset - offset.clamp(-DEAD_ZONE, DEAD_ZONE);
+    let target = camera_translation + outside_dead_zone;
+
+    // Snap to the player when a map was just loaded instead of panning there.
+    let next = if bounds.is_changed() {
+        player_translation
+    } else {
+        let smoothing = 1. - (-FOLLOW_SHARPNESS * time.delta_seconds()).exp();
+        camera_translation.lerp(target, smoothing)
+    };
+
+    let half_extent = Vec2::new(projection.right, projection.top) * projection.scale;
+    camera_transform.translation.x = clamp_axis(next.x, half_extent.x, bounds.min.x, bounds.max.x);
+    camera_transform.translation.y = clamp_axis(next.y, half_extent.y, bounds.min.y, bounds.max.y);
+}
+
+pub fn zoom_camera(
+    level: Res<CurrentLevel>,
+    time: Res<Time>,
+    mut wheel: EventReader<MouseWheel>,
+    gamepads: Res<Gamepads>,
+    triggers: Res<Axis<GamepadButton>>,
+    mut camera: Query<(&mut Zoom, &mut OrthographicProjection), With<MainCamera>>,
+) {
+    let (mut zoom, mut projection) = camera.single_mut();
+
+    if level.is_changed() {
+        zoom.target = level.camera_scale;
+        projection.scale = level.camera_scale;
+    }
+
+    let mut lines: f32 = wheel
+        .iter()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y,
+            MouseScrollUnit::Pixel => event.y / PIXELS_PER_LINE,
+        })
+        .sum();
+
+    for gamepad in gamepads.iter() {
+        let pressure = |button| {
+            triggers
+                .get(GamepadButton(*gamepad, button))
+                .unwrap_or_default()
+        };
+        lines += (pressure(GamepadButtonType::RightTrigger2)
+            - pressure(GamepadButtonType::LeftTrigger2))
+            * TRIGGER_ZOOM_RATE
+            * time.delta_seconds();
+    }
+
+    // Scrolling up zooms in, which means a smaller projection scale.
+    zoom.target = (zoom.target * ZOOM_STEP.powf(-lines)).clamp(MIN_ZOOM, MAX_ZOOM);
+
+    if (projection.scale - zoom.target).abs() > f32::EPSILON {
+        let smoothing = 1. - (-ZOOM_SHARPNESS * time.delta_seconds()).exp();
+        projection.scale += (zoom.target - projection.scale) * smoothing;
+    }
+}
+
+/// Smooth noise in `-1..=1`, `phase` decorrelates the different axes.
+fn wobble(time: f32, phase: f32) -> f32 {
+    let t = time * SHAKE_FREQUENCY + phase;
+    (t.sin() + (t * 2.3 + phase).sin()) * 0.5
+}
+
+pub fn shake_camera(
+    accessibility: Res<Accessibility>,
+    time: Res<Time>,
+    mut events: EventReader<ShakeEvent>,
+    mut camera: Query<(&mut Shake, &mut Transform), With<MainCamera>>,
+) {
+    let (mut shake, mut transform) = camera.single_mut();
+
+    for event in events.iter() {
+        shake.trauma = (shake.trauma + event.trauma).min(1.);
+    }
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_seconds()).max(0.);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let now = time.seconds_since_startup() as f32;
+    let strength = shake.trauma * shake.trauma * accessibility.screen_shake;
+    let offset = Vec2::new(wobble(now, 0.), wobble(now, 10.)) * MAX_SHAKE_OFFSET * strength;
+
+    // The follow system already removed last frame's offset.
+    transform.translation += offset.extend(0.);
+    transform.rotation = Quat::from_rotation_z(wobble(now, 20.) * MAX_SHAKE_ANGLE * strength);
+    shake.offset = offset;
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Empty space the asset pack leaves under the ground line of every sprite.
+const GROUND_MARGIN: f32 = 32.;
+const BASE_Z: f32 = 1.;
+const Z_PER_PIXEL: f32 = 0.001;
+
+/// Sprites standing on the ground are drawn in front of the ones above them,
+/// sorted by the point where they touch the ground.
+#[derive(Component)]
+pub struct YSort {
+    foot_offset: f32,
+}
+
+impl YSort {
+    /// Foot anchor for a sprite of the given height centered on its
+    /// translation.
+    pub fn for_height(height: f32) -> YSort {
+        YSort {
+            foot_offset: height / 2. - GROUND_MARGIN,
+        }
+    }
+}
+
+pub fn y_sort(mut sprites: Query<(&mut Transform, &YSort), Changed<Transform>>) {
+    for (mut transform, y_sort) in sprites.iter_mut() {
+        let foot = transform.translation.y - y_sort.foot_offset;
+        let z = BASE_Z - foot * Z_PER_PIXEL;
+
+        // Only write when needed, so sprites at rest are not marked as changed.
+        if (transform.translation.z - z).abs() > f32::EPSILON {
+            transform.translation.z = z;
+        }
+    }
+}
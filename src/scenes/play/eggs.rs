@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use crate::resources::prelude::Sprites;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EggKind {
+    Common,
+    /// Rare and worth more, but gone quickly.
+    Golden,
+    Crystal,
+    Ruby,
+    /// Costs points when picked up.
+    Rotten,
+}
+
+impl EggKind {
+    pub const ALL: [EggKind; 5] = [
+        EggKind::Common,
+        EggKind::Golden,
+        EggKind::Crystal,
+        EggKind::Ruby,
+        EggKind::Rotten,
+    ];
+
+    pub fn points(self) -> isize {
+        match self {
+            EggKind::Common => 1,
+            EggKind::Golden => 5,
+            EggKind::Crystal => 2,
+            EggKind::Ruby => 3,
+            EggKind::Rotten => -2,
+        }
+    }
+
+    /// Shortest time the egg stays on the ground, chickens add a random
+    /// extra on top.
+    pub fn lifetime(self) -> Duration {
+        match self {
+            EggKind::Common => Duration::from_secs(5),
+            EggKind::Golden => Duration::from_secs(1),
+            EggKind::Crystal | EggKind::Ruby => Duration::from_secs(4),
+            EggKind::Rotten => Duration::from_secs(8),
+        }
+    }
+
+    pub fn label(self) -> Option<&'static str> {
+        match self {
+            EggKind::Common => None,
+            EggKind::Golden => Some("golden!"),
+            EggKind::Crystal => Some("crystal!"),
+            EggKind::Ruby => Some("ruby!"),
+            EggKind::Rotten => Some("rotten..."),
+        }
+    }
+
+    pub fn tint(self) -> Color {
+        match self {
+            EggKind::Golden => Color::rgb(1.0, 0.85, 0.3),
+            EggKind::Rotten => Color::rgb(0.55, 0.65, 0.35),
+            _ => Color::WHITE,
+        }
+    }
+
+    pub fn scale(self) -> f32 {
+        match self {
+            EggKind::Golden => 1.3,
+            _ => 1.,
+        }
+    }
+
+    /// Rotten eggs cost points when delivered but don't count towards the
+    /// egg goal.
+    pub fn counts_for_goal(self) -> bool {
+        !matches!(self, EggKind::Rotten)
+    }
+
+    /// Rotten eggs go bad instead of hatching.
+    pub fn can_hatch(self) -> bool {
+        !matches!(self, EggKind::Rotten)
+    }
+
+    pub fn texture(self, sprites: &Sprites) -> Handle<Image> {
+        match self {
+            EggKind::Crystal => sprites.crystal.clone(),
+            EggKind::Ruby => sprites.ruby.clone(),
+            _ => sprites.egg.clone(),
+        }
+    }
+}
+
+/// Relative odds of each egg kind, in the order of [`EggKind::ALL`].
+#[derive(Debug, Clone, Copy)]
+pub struct EggTable([u32; 5]);
+
+impl EggTable {
+    pub const fn new(common: u32, golden: u32, crystal: u32, ruby: u32, rotten: u32) -> EggTable {
+        EggTable([common, golden, crystal, ruby, rotten])
+    }
+
+    /// Picks an egg kind with the combined odds of both tables, so a level
+    /// decides which eggs exist and a breed skews them.
+    pub fn pick(&self, modifier: &EggTable, rng: &mut impl Rng) -> EggKind {
+        let weights = self.0.iter().zip(modifier.0).map(|(a, b)| a * b);
+
+        match WeightedIndex::new(weights) {
+            Ok(distribution) => EggKind::ALL[distribution.sample(rng)],
+            Err(_) => EggKind::Common,
+        }
+    }
+}
+
+/// Kind of chicken, also put on eggs for the breed of the chick inside.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breed {
+    Hen,
+    /// Lays more precious eggs.
+    Fancy,
+    /// Lays more rotten eggs.
+    Scruffy,
+}
+
+impl Breed {
+    pub fn egg_table(self) -> EggTable {
+        match self {
+            Breed::Hen => EggTable::new(1, 1, 1, 1, 1),
+            Breed::Fancy => EggTable::new(1, 3, 2, 2, 0),
+            Breed::Scruffy => EggTable::new(1, 0, 1, 1, 3),
+        }
+    }
+}
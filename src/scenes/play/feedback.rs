@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::{Colors, Fonts},
+    ui::SimpleText,
+};
+
+use super::{ui::EggCounter, Despawn};
+
+const POPUP_DURATION: Duration = Duration::from_millis(900);
+const POPUP_RISE_SPEED: f32 = 80.;
+/// Popups start above the egg rather than on top of it.
+const POPUP_OFFSET: f32 = 48.;
+/// Drawn above the map but below the camera.
+const POPUP_Z: f32 = 600.;
+
+const PUNCH_DURATION: Duration = Duration::from_millis(250);
+/// Extra scale of the HUD counter at the peak of a punch.
+const PUNCH_SCALE: f32 = 0.4;
+
+/// Sent when the player puts an egg worth `points` in the basket.
+pub struct EggCollected {
+    pub position: Vec3,
+    pub points: isize,
+    pub label: Option<&'static str>,
+}
+
+/// Sent when the basket is emptied at a coop, `points` is what the `eggs`
+/// were worth together.
+pub struct EggsBanked {
+    pub position: Vec3,
+    pub points: isize,
+    pub eggs: usize,
+}
+
+/// Sent when harvested crops worth `points` are sold at a coop.
+pub struct CropsSold {
+    pub position: Vec3,
+    pub points: isize,
+    pub crops: usize,
+}
+
+/// World space text that rises and fades until its despawn timer ends.
+#[derive(Component)]
+pub struct Popup;
+
+/// Briefly scales a UI node up and back down.
+#[derive(Component)]
+pub struct Punch {
+    timer: Timer,
+}
+
+impl Default for Punch {
+    fn default() -> Punch {
+        let mut timer = Timer::new(PUNCH_DURATION, false);
+        timer.tick(PUNCH_DURATION);
+
+        Punch { timer }
+    }
+}
+
+fn spawn_popup(commands: &mut Commands, fonts: &Fonts, position: Vec3, value: String) {
+    let mut text = SimpleText::medium(value, &fonts.fredoka);
+    text.color(Colors::PRIMARY);
+
+    let position = position.truncate() + Vec2::Y * POPUP_OFFSET;
+    commands
+        .spawn_bundle(text.world(Transform::from_translation(position.extend(POPUP_Z))))
+        .insert(Popup)
+        .insert(Despawn(Timer::new(POPUP_DURATION, false)))
+        .insert(StateScoped(game::State::Play));
+}
+
+pub fn spawn_score_popups(
+    mut commands: Commands,
+    fonts: Res<Fonts>,
+    mut collected: EventReader<EggCollected>,
+    mut banked: EventReader<EggsBanked>,
+    mut sold: EventReader<CropsSold>,
+) {
+    for event in collected.iter() {
+        let value = match event.label {
+            Some(label) => format!("{:+} {label}", event.points),
+            None => format!("{:+}", event.points),
+        };
+        spawn_popup(&mut commands, &fonts, event.position, value);
+    }
+
+    for event in banked.iter() {
+        let value = format!("{:+} banked ({} eggs)", event.points, event.eggs);
+        spawn_popup(&mut commands, &fonts, event.position, value);
+    }
+
+    for event in sold.iter() {
+        let value = format!("{:+} sold ({} crops)", event.points, event.crops);
+        spawn_popup(&mut commands, &fonts, event.position, value);
+    }
+}
+
+pub fn animate_popups(
+    time: Res<Time>,
+    mut popups: Query<(&Despawn, &mut Transform, &mut Text), With<Popup>>,
+) {
+    for (despawn, mut transform, mut text) in popups.iter_mut() {
+        transform.translation.y += POPUP_RISE_SPEED * time.delta_seconds();
+
+        let alpha = 1. - despawn.0.percent();
+        for section in &mut text.sections {
+            section.style.color.set_a(alpha);
+        }
+    }
+}
+
+pub fn punch_counter(
+    time: Res<Time>,
+    mut banked: EventReader<EggsBanked>,
+    mut counter: Query<(&mut Punch, &mut Transform), With<EggCounter>>,
+) {
+    let Ok((mut punch, mut transform)) = counter.get_single_mut() else {
+        return;
+    };
+
+    if banked.iter().count() > 0 {
+        punch.timer.reset();
+    }
+
+    if punch.timer.finished() {
+        return;
+    }
+
+    punch.timer.tick(time.delta());
+    // Grows and shrinks back once over the punch.
+    let bump = (punch.timer.percent() * std::f32::consts::PI).sin();
+    transform.scale = Vec3::splat(1. + bump * PUNCH_SCALE);
+}
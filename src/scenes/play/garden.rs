@@ -0,0 +1,302 @@
+use bevy::prelude::{Plugin as BevyPlugin, *};
+use rand::seq::SliceRandom;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::{GameRng, Sprites},
+};
+
+use super::{
+    basket::{Coop, DELIVERY_DISTANCE},
+    feedback::CropsSold,
+    maps::{self, MapDefinition, MapObject},
+    Player, RngStep,
+};
+
+const USE_KEY: KeyCode = KeyCode::E;
+/// How close the player has to stand to a bed to work on it.
+const REACH: f32 = 90. * 90.;
+/// Seconds a watered crop takes to ripen.
+const GROW_SECS: f64 = 45.;
+/// Planted beds look dry until watered.
+const DRY_TINT: Color = Color::rgb(1.0, 0.9, 0.75);
+/// Height of the hint above the middle of the bed.
+const HINT_OFFSET: f32 = 72.;
+const HINT_SCALE: f32 = 0.5;
+/// Drawn above the characters standing around the bed.
+const HINT_Z: f32 = 500.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crop {
+    Beetroot,
+    Carrots,
+    Cucumbers,
+    Onions,
+    Radish,
+    Tomatoes,
+}
+
+impl Crop {
+    pub const ALL: [Crop; 6] = [
+        Crop::Beetroot,
+        Crop::Carrots,
+        Crop::Cucumbers,
+        Crop::Onions,
+        Crop::Radish,
+        Crop::Tomatoes,
+    ];
+
+    /// Bonus score when sold at the coop.
+    pub fn points(self) -> isize {
+        match self {
+            Crop::Radish => 1,
+            Crop::Beetroot | Crop::Carrots | Crop::Onions => 2,
+            Crop::Cucumbers | Crop::Tomatoes => 3,
+        }
+    }
+
+    fn bed_texture(self, sprites: &Sprites, ripe: bool) -> &Handle<Image> {
+        &sprites.garden.beds[self as usize][usize::from(ripe)]
+    }
+
+    pub fn texture(self, sprites: &Sprites) -> &Handle<Image> {
+        &sprites.garden.produce[self as usize]
+    }
+}
+
+/// What grows on a garden bed tile, growing starts once it is watered.
+#[derive(Clone, Default)]
+pub struct GardenBed {
+    crop: Option<Crop>,
+    watered_at: Option<f64>,
+}
+
+impl GardenBed {
+    fn ripe(&self, now: f64) -> bool {
+        self.watered_at
+            .is_some_and(|watered_at| now - watered_at >= GROW_SECS)
+    }
+}
+
+/// Crops the player carries, fed to chickens or sold at a coop.
+#[derive(Default)]
+pub struct Harvest {
+    crops: Vec<Crop>,
+}
+
+impl Harvest {
+    pub fn len(&self) -> usize {
+        self.crops.len()
+    }
+
+    pub fn take(&mut self) -> Option<Crop> {
+        self.crops.pop()
+    }
+}
+
+/// Score from crops sold this level, kept apart from the eggs counting
+/// toward the level goal.
+#[derive(Default)]
+pub struct CropBonus(pub usize);
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(game::State::Play).with_system(setup))
+            .add_system_set(
+                SystemSet::on_in_stack_update(game::State::Play)
+                    .with_system(tend_garden.label(RngStep::Garden))
+                    .with_system(update_bed_sprites)
+                    .with_system(show_tool_hint)
+                    .with_system(sell_crops),
+            );
+    }
+}
+
+/// Sprite of the garden bed on a map tile.
+#[derive(Component)]
+pub struct BedTile(usize, usize);
+
+/// Shows which tool the bed next to the player needs.
+#[derive(Component)]
+pub struct ToolHint;
+
+pub fn spawn_bed(commands: &mut Commands, sprites: &Sprites, x: usize, y: usize) {
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: sprites.garden.blank.clone(),
+            transform: Transform::from_translation(maps::get_vector_for_tile(x, y, 0.1)),
+            ..default()
+        })
+        .insert(BedTile(x, y))
+        .insert(StateScoped(game::State::Play));
+}
+
+fn setup(mut commands: Commands) {
+    commands.insert_resource(Harvest::default());
+    commands.insert_resource(CropBonus::default());
+    commands
+        .spawn_bundle(SpriteBundle {
+            transform: Transform::from_scale(Vec3::splat(HINT_SCALE)),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(ToolHint)
+        .insert(StateScoped(game::State::Play));
+}
+
+/// Bed tile within reach of the player, the closest one if there are several.
+fn bed_in_reach(map: &MapDefinition, player: Vec2) -> Option<(usize, usize, &GardenBed)> {
+    (0..map.height)
+        .flat_map(|y| (0..map.width).map(move |x| (x, y)))
+        .filter_map(|(x, y)| match map.object(x, y) {
+            Some(MapObject::GardenBed(bed)) => Some((x, y, bed)),
+            _ => None,
+        })
+        .map(|(x, y, bed)| {
+            let tile = maps::get_vector_for_tile(x, y, 0.).truncate();
+            (x, y, bed, tile.distance_squared(player))
+        })
+        .filter(|(.., distance)| *distance < REACH)
+        .min_by(|(.., a), (.., b)| a.total_cmp(b))
+        .map(|(x, y, bed, _)| (x, y, bed))
+}
+
+/// Plants, waters or harvests the bed next to the player, whichever it needs.
+fn tend_garden(
+    keys: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    mut map: ResMut<MapDefinition>,
+    mut harvest: ResMut<Harvest>,
+    player: Query<&Transform, With<Player>>,
+) {
+    if !keys.just_pressed(USE_KEY) {
+        return;
+    }
+
+    let now = time.seconds_since_startup();
+    let player = player.single().translation.truncate();
+    let Some((x, y, bed)) = bed_in_reach(&map, player) else {
+        return;
+    };
+
+    let tended = match bed.crop {
+        None => GardenBed {
+            crop: Crop::ALL.choose(&mut rng.0).copied(),
+            watered_at: None,
+        },
+        Some(_) if bed.watered_at.is_none() => GardenBed {
+            watered_at: Some(now),
+            ..bed.clone()
+        },
+        Some(crop) if bed.ripe(now) => {
+            harvest.crops.push(crop);
+            GardenBed::default()
+        }
+        Some(_) => return,
+    };
+
+    if let Some(bed) = map.garden_bed_mut(x, y) {
+        *bed = tended;
+    }
+}
+
+fn update_bed_sprites(
+    time: Res<Time>,
+    sprites: Res<Sprites>,
+    map: Res<MapDefinition>,
+    mut beds: Query<(&BedTile, &mut Handle<Image>, &mut Sprite)>,
+) {
+    let now = time.seconds_since_startup();
+
+    for (tile, mut handle, mut sprite) in beds.iter_mut() {
+        let Some(MapObject::GardenBed(bed)) = map.object(tile.0, tile.1) else {
+            continue;
+        };
+        let texture = match bed.crop {
+            Some(crop) => crop.bed_texture(&sprites, bed.ripe(now)),
+            None => &sprites.garden.blank,
+        };
+        let color = if bed.crop.is_some() && bed.watered_at.is_none() {
+            DRY_TINT
+        } else {
+            Color::WHITE
+        };
+
+        if *handle != *texture {
+            *handle = texture.clone();
+        }
+        if sprite.color != color {
+            sprite.color = color;
+        }
+    }
+}
+
+fn show_tool_hint(
+    time: Res<Time>,
+    sprites: Res<Sprites>,
+    map: Res<MapDefinition>,
+    player: Query<&Transform, (With<Player>, Without<ToolHint>)>,
+    mut hint: Query<(&mut Transform, &mut Handle<Image>, &mut Visibility), With<ToolHint>>,
+) {
+    let now = time.seconds_since_startup();
+    let player = player.single().translation.truncate();
+    let (mut transform, mut handle, mut visibility) = hint.single_mut();
+
+    let needed = bed_in_reach(&map, player).and_then(|(x, y, bed)| {
+        let texture = match bed.crop {
+            None => &sprites.garden.shovel,
+            Some(_) if bed.watered_at.is_none() => &sprites.garden.watering_can,
+            Some(crop) if bed.ripe(now) => crop.texture(&sprites),
+            Some(_) => return None,
+        };
+        Some((maps::get_vector_for_tile(x, y, 0.).truncate(), texture))
+    });
+
+    if visibility.is_visible != needed.is_some() {
+        visibility.is_visible = needed.is_some();
+    }
+    let Some((bed, texture)) = needed else {
+        return;
+    };
+
+    let position = Vec3::new(bed.x, bed.y + HINT_OFFSET, HINT_Z);
+    if transform.translation != position {
+        transform.translation = position;
+    }
+    if *handle != *texture {
+        *handle = texture.clone();
+    }
+}
+
+fn sell_crops(
+    mut harvest: ResMut<Harvest>,
+    mut bonus: ResMut<CropBonus>,
+    mut sold: EventWriter<CropsSold>,
+    player: Query<&Transform, With<Player>>,
+    coops: Query<&Transform, With<Coop>>,
+) {
+    if harvest.crops.is_empty() {
+        return;
+    }
+
+    let player = player.single().translation.truncate();
+    let Some(coop) = coops
+        .iter()
+        .find(|coop| coop.translation.truncate().distance_squared(player) < DELIVERY_DISTANCE)
+    else {
+        return;
+    };
+
+    let count = harvest.crops.len();
+    let points = harvest.crops.drain(..).map(Crop::points).sum();
+    bonus.0 = bonus.0.saturating_add_signed(points);
+
+    sold.send(CropsSold {
+        position: coop.translation,
+        points,
+        crops: count,
+    });
+}
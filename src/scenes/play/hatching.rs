@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use bevy::prelude::{Plugin as BevyPlugin, *};
+use rand::Rng;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::{GameRng, Sprites},
+};
+
+use super::{
+    despawn_timers,
+    eggs::{Breed, EggKind},
+    hazards::TerrainMotion,
+    maps::{self, MapDefinition},
+    navigation::Wander,
+    Character, Chicken, Collidable, Despawn, Egg, Facing, RngStep, Walk, YSort, SPRITE_HEIGHT,
+};
+
+/// Chance that an egg left lying until it expires hatches.
+const HATCH_CHANCE: f64 = 0.3;
+const GROW_TIME: Duration = Duration::from_secs(40);
+/// Size of a fresh chick compared to a grown chicken.
+const CHICK_SCALE: f32 = 0.5;
+
+/// Young chicken, it lays no eggs until grown.
+#[derive(Component)]
+pub struct Chick {
+    breed: Breed,
+    grow: Timer,
+}
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_in_stack_update(game::State::Play)
+                .with_system(
+                    hatch_eggs
+                        .label(RngStep::Hatch)
+                        .after(RngStep::Lay)
+                        .after(despawn_timers),
+                )
+                .with_system(grow_chicks),
+        );
+    }
+}
+
+/// Rolls for every egg whose despawn timer ran out this frame, so it has to
+/// run after the timers are ticked.
+fn hatch_eggs(
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    map: Res<MapDefinition>,
+    sprites: Res<Sprites>,
+    eggs: Query<(&Transform, &EggKind, &Breed, &Despawn), With<Egg>>,
+    flock: Query<(), Or<(With<Chicken>, With<Chick>)>>,
+) {
+    let mut population = flock.iter().count();
+
+    for (transform, kind, breed, despawn) in eggs.iter() {
+        if population >= map.chicken_cap {
+            return;
+        }
+        if !despawn.0.just_finished() || !kind.can_hatch() || !rng.0.gen_bool(HATCH_CHANCE) {
+            continue;
+        }
+
+        population += 1;
+        let position = transform.translation;
+        commands
+            .spawn_bundle(SpriteBundle {
+                texture: sprites.chick.down.clone(),
+                transform: Transform::from_translation(position)
+                    .with_scale(Vec3::splat(CHICK_SCALE)),
+                ..default()
+            })
+            .insert(Chick {
+                breed: *breed,
+                grow: Timer::new(GROW_TIME, false),
+            })
+            .insert(Character::Chick)
+            .insert(Facing::Down)
+            .insert(Walk::new(position).with_scale(CHICK_SCALE))
+            .insert(YSort::for_height(SPRITE_HEIGHT))
+            .insert(TerrainMotion::default())
+            .insert(Wander::default())
+            .insert(Collidable { can_move: true })
+            .insert(StateScoped(game::State::Play));
+    }
+}
+
+fn grow_chicks(
+    mut commands: Commands,
+    time: Res<Time>,
+    sprites: Res<Sprites>,
+    mut chicks: Query<(Entity, &mut Chick, &mut Walk, &Transform)>,
+) {
+    for (entity, mut chick, mut walk, transform) in chicks.iter_mut() {
+        if chick.grow.tick(time.delta()).just_finished() {
+            commands.entity(entity).despawn_recursive();
+            maps::spawn_chicken(&mut commands, &sprites, transform.translation, chick.breed);
+            continue;
+        }
+
+        // The walk animation applies it to the transform.
+        walk.scale = CHICK_SCALE + (1. - CHICK_SCALE) * chick.grow.percent();
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::resources::prelude::Sprites;
+
+use super::{
+    camera::ShakeEvent,
+    maps::{MapDefinition, MapObject},
+};
+
+/// Spikes stay down for the rest of the cycle.
+const SPIKE_CYCLE: Duration = Duration::from_secs(3);
+const SPIKE_ACTIVE: Duration = Duration::from_secs(1);
+/// Longer than the spikes stay up, so a stunned creature is free to leave.
+const STUN_DURATION: Duration = Duration::from_millis(1500);
+const SPIKE_TRAUMA: f32 = 0.3;
+/// Share of the usual movement kept while wading through goo.
+const GOO_SPEED: f32 = 0.4;
+/// How quickly velocity follows the intended movement on ice, per second.
+const ICE_GRIP: f32 = 1.5;
+
+/// Runs movement systems between recording where everything started the
+/// frame and applying terrain effects to where it ended up.
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TerrainStep {
+    Record,
+    Apply,
+}
+
+/// Anything walking on the map, terrain effects apply to its movement
+/// during a frame whichever system caused it.
+#[derive(Component, Default)]
+pub struct TerrainMotion {
+    start: Vec2,
+    velocity: Vec2,
+}
+
+#[derive(Component)]
+pub struct Stunned(Timer);
+
+#[derive(Component)]
+pub struct Spikes;
+
+/// Shared timing of all spikes on the map.
+pub struct SpikeCycle(Timer);
+
+impl Default for SpikeCycle {
+    fn default() -> SpikeCycle {
+        SpikeCycle(Timer::new(SPIKE_CYCLE, true))
+    }
+}
+
+impl SpikeCycle {
+    fn active(&self) -> bool {
+        self.0.elapsed() < SPIKE_ACTIVE
+    }
+}
+
+pub fn record_motion_start(mut movers: Query<(&Transform, &mut TerrainMotion)>) {
+    for (transform, mut motion) in movers.iter_mut() {
+        motion.start = transform.translation.truncate();
+    }
+}
+
+pub fn cycle_spikes(
+    time: Res<Time>,
+    sprites: Res<Sprites>,
+    mut cycle: ResMut<SpikeCycle>,
+    mut spikes: Query<&mut Handle<Image>, With<Spikes>>,
+) {
+    let was_active = cycle.active();
+    cycle.0.tick(time.delta());
+
+    if cycle.active() == was_active {
+        return;
+    }
+
+    let texture = if cycle.active() {
+        &sprites.hazards.spikes_active
+    } else {
+        &sprites.hazards.spikes
+    };
+    for mut handle in spikes.iter_mut() {
+        *handle = texture.clone();
+    }
+}
+
+/// Keeps `target` out of water, sliding along the shore when only one axis
+/// is blocked.
+fn avoid_water(map: &MapDefinition, start: Vec2, target: Vec2) -> Vec2 {
+    let blocked = |position: Vec2| matches!(map.tile_at(position), Some(MapObject::Water));
+
+    if !blocked(target) {
+        target
+    } else if !blocked(Vec2::new(target.x, start.y)) {
+        Vec2::new(target.x, start.y)
+    } else if !blocked(Vec2::new(start.x, target.y)) {
+        Vec2::new(start.x, target.y)
+    } else {
+        start
+    }
+}
+
+pub fn apply_terrain(
+    mut commands: Commands,
+    map: Res<MapDefinition>,
+    cycle: Res<SpikeCycle>,
+    time: Res<Time>,
+    mut shakes: EventWriter<ShakeEvent>,
+    mut movers: Query<(
+        Entity,
+        &mut Transform,
+        &mut TerrainMotion,
+        Option<&mut Stunned>,
+    )>,
+) {
+    let delta = time.delta_seconds();
+    if delta <= 0. {
+        return;
+    }
+
+    for (entity, mut transform, mut motion, stunned) in movers.iter_mut() {
+        let start = motion.start;
+        let mut target = transform.translation.truncate();
+
+        let mut is_stunned = false;
+        if let Some(mut stunned) = stunned {
+            if stunned.0.tick(time.delta()).finished() {
+                commands.entity(entity).remove::<Stunned>();
+            } else {
+                is_stunned = true;
+                target = start;
+            }
+        }
+
+        let wanted_velocity = (target - start) / delta;
+        match map.tile_at(start) {
+            Some(MapObject::Goo) => {
+                motion.velocity = wanted_velocity * GOO_SPEED;
+            }
+            Some(MapObject::Ice) => {
+                let grip = (ICE_GRIP * delta).min(1.);
+                motion.velocity = motion.velocity.lerp(wanted_velocity, grip);
+            }
+            _ => motion.velocity = wanted_velocity,
+        }
+        if is_stunned {
+            motion.velocity = Vec2::ZERO;
+        }
+
+        target = avoid_water(&map, start, start + motion.velocity * delta);
+        motion.velocity = (target - start) / delta;
+
+        if !is_stunned && cycle.active() && matches!(map.tile_at(target), Some(MapObject::Spikes)) {
+            commands
+                .entity(entity)
+                .insert(Stunned(Timer::new(STUN_DURATION, false)));
+            shakes.send(ShakeEvent {
+                trauma: SPIKE_TRAUMA,
+            });
+        }
+
+        if target != transform.translation.truncate() {
+            transform.translation.x = target.x;
+            transform.translation.y = target.y;
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::HashSet, time::Duration};
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::Sprites,
+};
+
+use super::{Despawn, Egg, MainCamera};
+
+/// Eggs start blinking when they have less time than this left.
+const BLINK_WARNING: Duration = Duration::from_secs(3);
+/// Blinks per second right before an egg vanishes, it starts at half that.
+const BLINK_RATE: f32 = 8.;
+const BLINK_ALPHA: f32 = 0.25;
+
+/// Distance kept between arrows and the screen edge, in screen pixels.
+const ARROW_MARGIN: f32 = 48.;
+const ARROW_SCALE: f32 = 0.5;
+/// Drawn above the map but below the camera.
+const ARROW_Z: f32 = 500.;
+
+const CALM_COLOR: Color = Color::rgb(0.4, 0.9, 0.4);
+const HURRY_COLOR: Color = Color::rgb(1.0, 0.85, 0.1);
+const URGENT_COLOR: Color = Color::rgb(1.0, 0.13, 0.13);
+
+/// Screen edge arrow pointing at an off-screen egg.
+#[derive(Component)]
+pub struct EggArrow(Entity);
+
+fn time_left(despawn: &Despawn) -> Duration {
+    despawn.0.duration().saturating_sub(despawn.0.elapsed())
+}
+
+fn urgency_color(time_left: Duration) -> Color {
+    if time_left > BLINK_WARNING * 2 {
+        CALM_COLOR
+    } else if time_left > BLINK_WARNING {
+        HURRY_COLOR
+    } else {
+        URGENT_COLOR
+    }
+}
+
+pub fn blink_expiring_eggs(mut eggs: Query<(&Despawn, &mut Sprite), With<Egg>>) {
+    for (despawn, mut sprite) in eggs.iter_mut() {
+        let left = time_left(despawn);
+        let alpha = if left < BLINK_WARNING {
+            // The rate climbs linearly from half to the full rate as time runs
+            // out, the phase is that rate integrated over the warning so far.
+            let warning = BLINK_WARNING.as_secs_f32();
+            let blinking = warning - left.as_secs_f32();
+            let phase = BLINK_RATE * (blinking / 2. + blinking * blinking / (4. * warning));
+            if phase.fract() < 0.5 {
+                BLINK_ALPHA
+            } else {
+                1.
+            }
+        } else {
+            1.
+        };
+
+        if (sprite.color.a() - alpha).abs() > f32::EPSILON {
+            sprite.color.set_a(alpha);
+        }
+    }
+}
+
+pub fn point_to_offscreen_eggs(
+    mut commands: Commands,
+    sprites: Res<Sprites>,
+    camera: Query<(&Transform, &OrthographicProjection), With<MainCamera>>,
+    eggs: Query<(Entity, &Transform, &Despawn), With<Egg>>,
+    mut arrows: Query<
+        (
+            Entity,
+            &EggArrow,
+            &mut Transform,
+            &mut Sprite,
+            &mut Handle<Image>,
+        ),
+        (Without<Egg>, Without<MainCamera>),
+    >,
+) {
+    let Ok((camera_transform, projection)) = camera.get_single() else {
+        return;
+    };
+    let center = camera_transform.translation.truncate();
+    let half_extent = Vec2::new(projection.right, projection.top) * projection.scale;
+    let inner = (half_extent - ARROW_MARGIN * projection.scale).max(Vec2::ONE);
+
+    // Where the arrow for an egg goes and which way it points, or `None` when
+    // the egg is on screen.
+    let place = |egg: Vec2| {
+        let offset = egg - center;
+        if offset.x.abs() <= half_extent.x && offset.y.abs() <= half_extent.y {
+            return None;
+        }
+
+        let to_side = inner.x / offset.x.abs().max(f32::EPSILON);
+        let to_top = inner.y / offset.y.abs().max(f32::EPSILON);
+        let (texture, scale) = if to_side < to_top {
+            let texture = if offset.x < 0. {
+                &sprites.arrows.left
+            } else {
+                &sprites.arrows.right
+            };
+            (texture, to_side)
+        } else {
+            let texture = if offset.y < 0. {
+                &sprites.arrows.down
+            } else {
+                &sprites.arrows.up
+            };
+            (texture, to_top)
+        };
+
+        Some((center + offset * scale, texture))
+    };
+
+    let mut pointed = HashSet::new();
+    for (arrow_entity, arrow, mut transform, mut sprite, mut texture) in arrows.iter_mut() {
+        let placed = eggs
+            .get(arrow.0)
+            .ok()
+            .and_then(|(_, egg, despawn)| Some((place(egg.translation.truncate())?, despawn)));
+        let Some(((position, arrow_texture), despawn)) = placed else {
+            commands.entity(arrow_entity).despawn_recursive();
+            continue;
+        };
+
+        transform.translation = position.extend(ARROW_Z);
+        transform.scale = Vec3::splat(ARROW_SCALE * projection.scale);
+        sprite.color = urgency_color(time_left(despawn));
+        if *texture != *arrow_texture {
+            *texture = arrow_texture.clone();
+        }
+        pointed.insert(arrow.0);
+    }
+
+    for (egg_entity, egg, despawn) in eggs.iter() {
+        if pointed.contains(&egg_entity) {
+            continue;
+        }
+        let Some((position, texture)) = place(egg.translation.truncate()) else {
+            continue;
+        };
+
+        commands
+            .spawn_bundle(SpriteBundle {
+                texture: texture.clone(),
+                sprite: Sprite {
+                    color: urgency_color(time_left(despawn)),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(ARROW_Z))
+                    .with_scale(Vec3::splat(ARROW_SCALE * projection.scale)),
+                ..default()
+            })
+            .insert(EggArrow(egg_entity))
+            .insert(StateScoped(game::State::Play));
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::{Plugin as BevyPlugin, *};
+
+use crate::{game, resources::prelude::Sprites};
+
+use super::{
+    maps::MapDefinition,
+    navigation::{self, NavGrid},
+    ChickensRescued, Player, PICKUP_DISTANCE,
+};
+
+/// How close the player has to get to a locked door to open it.
+const OPEN_DISTANCE: f32 = 90. * 90.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    Golden,
+    Silver,
+}
+
+impl KeyKind {
+    pub fn key_texture(self, sprites: &Sprites) -> &Handle<Image> {
+        match self {
+            KeyKind::Golden => &sprites.keys.golden_key,
+            KeyKind::Silver => &sprites.keys.silver_key,
+        }
+    }
+
+    pub fn door_texture(self, sprites: &Sprites) -> &Handle<Image> {
+        match self {
+            KeyKind::Golden => &sprites.keys.golden_door,
+            KeyKind::Silver => &sprites.keys.silver_door,
+        }
+    }
+}
+
+/// Key lying on the map, waiting to be picked up.
+#[derive(Component)]
+pub struct Key(pub KeyKind);
+
+/// Locked door on a map tile, it blocks like a fence until opened.
+#[derive(Component)]
+pub struct Door {
+    pub kind: KeyKind,
+    pub tile: (usize, usize),
+}
+
+/// Chicken walled off from the player, it counts as rescued once a door
+/// opens the way to it.
+#[derive(Component)]
+pub struct Trapped;
+
+/// Keys the player carries, each opens a single door.
+#[derive(Default)]
+pub struct KeyRing {
+    keys: Vec<KeyKind>,
+}
+
+impl KeyRing {
+    pub fn keys(&self) -> &[KeyKind] {
+        &self.keys
+    }
+
+    fn take(&mut self, kind: KeyKind) {
+        if let Some(index) = self.keys.iter().position(|key| *key == kind) {
+            self.keys.remove(index);
+        }
+    }
+}
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_in_stack_update(game::State::Play)
+                .with_system(pick_up_keys)
+                .with_system(open_doors)
+                .with_system(free_trapped_chickens.after(navigation::update_nav_grid)),
+        );
+    }
+}
+
+fn pick_up_keys(
+    mut commands: Commands,
+    mut ring: ResMut<KeyRing>,
+    player: Query<&Transform, With<Player>>,
+    keys: Query<(Entity, &Transform, &Key)>,
+) {
+    let player = player.single().translation.truncate();
+
+    for (entity, transform, key) in keys.iter() {
+        if transform.translation.truncate().distance_squared(player) < PICKUP_DISTANCE {
+            ring.keys.push(key.0);
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn open_doors(
+    mut commands: Commands,
+    mut ring: ResMut<KeyRing>,
+    mut map: ResMut<MapDefinition>,
+    player: Query<&Transform, With<Player>>,
+    doors: Query<(Entity, &Transform, &Door)>,
+) {
+    let player = player.single().translation.truncate();
+
+    for (entity, transform, door) in doors.iter() {
+        if transform.translation.truncate().distance_squared(player) < OPEN_DISTANCE
+            && ring.keys().contains(&door.kind)
+        {
+            ring.take(door.kind);
+            map.open_door(door.tile.0, door.tile.1);
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn free_trapped_chickens(
+    mut commands: Commands,
+    map: Res<MapDefinition>,
+    grid: Res<NavGrid>,
+    mut rescued: ResMut<ChickensRescued>,
+    trapped: Query<(Entity, &Transform), With<Trapped>>,
+) {
+    if !grid.is_changed() {
+        return;
+    }
+
+    for (entity, transform) in trapped.iter() {
+        if map.reachable(&grid, transform.translation.truncate()) {
+            commands.entity(entity).remove::<Trapped>();
+            rescued.0 += 1;
+        }
+    }
+}
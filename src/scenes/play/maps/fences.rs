@@ -0,0 +1,89 @@
+use super::MapDefinition;
+
+/// Picks the fence piece for a fence tile from which of its four neighbours
+/// are fences too. Pieces are named after the sides they connect to.
+pub fn tile_name(map_def: &MapDefinition, x: usize, y: usize) -> &'static str {
+    let up = y > 0 && map_def.joins_fences(x, y - 1);
+    let down = map_def.joins_fences(x, y + 1);
+    let left = x > 0 && map_def.joins_fences(x - 1, y);
+    let right = map_def.joins_fences(x + 1, y);
+
+    match (up, down, left, right) {
+        // A lone post has no piece of its own, the top cap looks closest.
+        (false, _, false, false) => "top",
+        (true, false, false, false) => "bottom",
+        (false, false, false, true) => "left",
+        (false, false, true, false) => "right",
+        (true, true, false, false) => "vertical",
+        (false, false, true, true) => "horizontal",
+        (false, true, false, true) => "corner_bottom_right",
+        (true, false, false, true) => "corner_top_right",
+        (false, true, true, false) => "corner_bottom_left",
+        (true, false, true, false) => "corner_top_left",
+        (false, true, true, true) => "t_down",
+        (true, false, true, true) => "t_top",
+        (true, true, true, false) => "t_left",
+        (true, true, false, true) => "t_right",
+        (true, true, true, true) => "x",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::parse_layout;
+    use super::*;
+
+    fn map(rows: &[&str]) -> MapDefinition {
+        MapDefinition {
+            width: rows[0].len(),
+            height: rows.len(),
+            map_objects: parse_layout(rows),
+            ..MapDefinition::new()
+        }
+    }
+
+    #[test]
+    fn picks_corners_and_sides_of_a_pen() {
+        let map = map(&["###", "#.#", "###"]);
+
+        assert_eq!(tile_name(&map, 0, 0), "corner_bottom_right");
+        assert_eq!(tile_name(&map, 2, 0), "corner_bottom_left");
+        assert_eq!(tile_name(&map, 0, 2), "corner_top_right");
+        assert_eq!(tile_name(&map, 2, 2), "corner_top_left");
+        assert_eq!(tile_name(&map, 1, 0), "horizontal");
+        assert_eq!(tile_name(&map, 0, 1), "vertical");
+    }
+
+    #[test]
+    fn picks_cross_and_ends() {
+        let map = map(&[".#.", "###", ".#."]);
+
+        assert_eq!(tile_name(&map, 1, 1), "x");
+        assert_eq!(tile_name(&map, 1, 0), "top");
+        assert_eq!(tile_name(&map, 1, 2), "bottom");
+        assert_eq!(tile_name(&map, 0, 1), "left");
+        assert_eq!(tile_name(&map, 2, 1), "right");
+    }
+
+    #[test]
+    fn picks_t_pieces() {
+        let map = map(&["###", ".#."]);
+
+        assert_eq!(tile_name(&map, 1, 0), "t_down");
+    }
+
+    #[test]
+    fn lone_post_uses_top_cap() {
+        let map = map(&["...", ".#.", "..."]);
+
+        assert_eq!(tile_name(&map, 1, 1), "top");
+    }
+
+    #[test]
+    fn doors_join_fences() {
+        let map = map(&["#D#"]);
+
+        assert_eq!(tile_name(&map, 0, 0), "left");
+        assert_eq!(tile_name(&map, 2, 0), "right");
+    }
+}
@@ -1,24 +1,64 @@
-use crate::game;
-use bevy::{
-    prelude::{Plugin as BevyPlugin, *},
-    sprite::Anchor,
+use std::time::Duration;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::{Sprites, Tileset, Tilesets},
 };
+use bevy::prelude::{Plugin as BevyPlugin, *};
+
+mod fences;
 
-use super::{Chicken, Collidable, GameplayObject, Pet, Player, CHICKEN_EGG_COOLDOWN};
+use super::{
+    basket::{Basket, Coop},
+    camera::MapBounds,
+    eggs::{Breed, EggTable},
+    garden::{self, GardenBed},
+    hazards::{Spikes, TerrainMotion},
+    keys::{Door, Key, KeyKind, KeyRing, Trapped},
+    minimap::{self, MinimapSettings},
+    navigation::{NavGrid, Wander},
+    needs::{self, Fountain, Needs},
+    pet::{self, PetCommand},
+    predators::PredatorSpawner,
+    Character, Chicken, Collidable, CurrentLevel, Facing, Pet, Player, Walk, YSort,
+    CHICKEN_EGG_COOLDOWN, SPRITE_HEIGHT,
+};
 
 #[derive(Clone)]
-enum MapObject {
+pub enum MapObject {
     Plain,
     Hole,
     Fence,
+    Coop,
+    /// Stuns whoever stands on it while the spikes are up.
+    Spikes,
+    Goo,
+    Ice,
+    Water,
+    Bridge,
+    /// Chickens drink from it.
+    Fountain,
+    GardenBed(GardenBed),
+    WaterSign,
+    Key(KeyKind),
+    /// Blocks like a fence until opened with a matching key.
+    Door(KeyKind),
 }
 
+/// Layout of the current level, inserted as a resource when play starts.
 #[derive(Clone)]
-struct MapDefinition {
-    width: usize,
-    height: usize,
+pub struct MapDefinition {
+    pub width: usize,
+    pub height: usize,
+    egg_goal: usize,
+    basket_capacity: usize,
+    predator_interval: Option<Duration>,
+    camera_scale: f32,
     player_spawn: (usize, usize),
-    chicken_spawns: Vec<(usize, usize)>,
+    pub egg_table: EggTable,
+    chicken_spawns: Vec<(usize, usize, Breed)>,
+    /// Eggs stop hatching once this many chickens and chicks are around.
+    pub chicken_cap: usize,
     map_objects: Vec<Vec<MapObject>>,
 }
 
@@ -30,219 +70,469 @@ struct MapDefinition {
  *
  */
 
-const TILE_WIDTH: usize = 128;
-const TILE_HEIGHT: usize = 63;
+pub const TILE_WIDTH: usize = 128;
+pub const TILE_HEIGHT: usize = 63;
 
 impl MapDefinition {
-    fn new() -> MapDefinition {
-        let map_objects_row: Vec<MapObject> = vec![
-            MapObject::Plain,
-            MapObject::Plain,
-            MapObject::Plain,
-            MapObject::Hole,
-            MapObject::Plain,
-            MapObject::Plain,
-            MapObject::Hole,
-            MapObject::Plain,
-            MapObject::Hole,
-            MapObject::Plain,
-            MapObject::Plain,
-            MapObject::Plain,
-        ];
+    fn for_level(level: usize) -> Option<MapDefinition> {
+        match level {
+            1 => Some(MapDefinition::new()),
+            _ => None,
+        }
+    }
 
+    fn new() -> MapDefinition {
         MapDefinition {
             width: 12,
             height: 8,
+            egg_goal: 15,
+            basket_capacity: 5,
+            predator_interval: Some(Duration::from_secs(20)),
+            camera_scale: 1.,
             player_spawn: (4, 3),
-            chicken_spawns: vec![(3, 2), (2, 2), (3, 3), (4, 4), (5, 5)],
-            map_objects: vec![
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
-                map_objects_row.clone(),
+            egg_table: EggTable::new(20, 2, 3, 3, 2),
+            chicken_cap: 12,
+            chicken_spawns: vec![
+                (1, 3, Breed::Hen),
+                (2, 2, Breed::Hen),
+                (2, 4, Breed::Fancy),
+                (4, 4, Breed::Hen),
+                (5, 5, Breed::Scruffy),
+                // Locked in the pen behind the golden door.
+                (10, 1, Breed::Fancy),
             ],
+            map_objects: parse_layout(&[
+                "############",
+                "#.Ko..obo#.#",
+                "#..o..obo#.#",
+                "#..o..ob..D#",
+                "#F.o..o.o###",
+                "#..ogg....C#",
+                "#ii.^.o~=~!#",
+                "############",
+            ]),
+        }
+    }
+
+    /// Area covered by the map sprites, tiles are centered on their grid
+    /// position and the tallest ones reach half a sprite above it.
+    fn bounds(&self) -> MapBounds {
+        let last_tile = get_vector_for_tile(self.width - 1, self.height - 1, 0.).truncate();
+        let half_tile = Vec2::new(TILE_WIDTH as f32, SPRITE_HEIGHT) / 2.;
+
+        MapBounds {
+            min: Vec2::new(-half_tile.x, last_tile.y - half_tile.y),
+            max: Vec2::new(last_tile.x + half_tile.x, half_tile.y),
+        }
+    }
+
+    pub fn object(&self, x: usize, y: usize) -> Option<&MapObject> {
+        self.map_objects.get(y).and_then(|row| row.get(x))
+    }
+
+    /// Object on the tile covering a world position.
+    pub fn tile_at(&self, world: Vec2) -> Option<&MapObject> {
+        let grid = grid_position(world).round();
+        if grid.x < 0. || grid.y < 0. {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        self.object(grid.x as usize, grid.y as usize)
+    }
+
+    /// Plain tiles just inside the map border, where predators sneak in and
+    /// out. Tiles walled off from where the player starts, like a locked pen,
+    /// don't count.
+    pub fn edge_tiles(&self, grid: &NavGrid) -> Vec<(usize, usize)> {
+        let (last_x, last_y) = (self.width.saturating_sub(2), self.height.saturating_sub(2));
+
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| x == 1 || y == 1 || x == last_x || y == last_y)
+            .filter(|&(x, y)| matches!(self.object(x, y), Some(MapObject::Plain)))
+            .filter(|&(x, y)| self.reachable(grid, get_vector_for_tile(x, y, 0.).truncate()))
+            .collect()
+    }
+
+    /// Whether the player can walk from where they start to `world`.
+    pub fn reachable(&self, grid: &NavGrid, world: Vec2) -> bool {
+        let spawn = get_vector_for_tile(self.player_spawn.0, self.player_spawn.1, 0.);
+        grid.find_path(spawn.truncate(), world).is_some()
+    }
+
+    /// Whether fences connect to the tile, doors sit in fence lines so they
+    /// count too.
+    fn joins_fences(&self, x: usize, y: usize) -> bool {
+        matches!(
+            self.object(x, y),
+            Some(MapObject::Fence | MapObject::Door(_))
+        )
+    }
+
+    pub fn garden_bed_mut(&mut self, x: usize, y: usize) -> Option<&mut GardenBed> {
+        match self.map_objects.get_mut(y).and_then(|row| row.get_mut(x)) {
+            Some(MapObject::GardenBed(bed)) => Some(bed),
+            _ => None,
+        }
+    }
+
+    pub fn open_door(&mut self, x: usize, y: usize) {
+        if let Some(tile) = self.map_objects.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *tile = MapObject::Plain;
         }
     }
 }
 
+/// Builds map objects from rows of characters, `#` is a fence, `o` a hole, `C`
+/// a coop, `^` spikes, `g` goo, `i` ice, `~` water, `=` a bridge, `!` a water
+/// sign, `F` a fountain, `b` a garden bed, `K`/`k` a golden/silver key, `D`/`d`
+/// a golden/silver door and anything else plain ground.
+fn parse_layout(rows: &[&str]) -> Vec<Vec<MapObject>> {
+    rows.iter()
+        .map(|row| {
+            row.chars()
+                .map(|tile| match tile {
+                    '#' => MapObject::Fence,
+                    'o' => MapObject::Hole,
+                    'C' => MapObject::Coop,
+                    '^' => MapObject::Spikes,
+                    'g' => MapObject::Goo,
+                    'i' => MapObject::Ice,
+                    '~' => MapObject::Water,
+                    '=' => MapObject::Bridge,
+                    '!' => MapObject::WaterSign,
+                    'F' => MapObject::Fountain,
+                    'b' => MapObject::GardenBed(GardenBed::default()),
+                    'K' => MapObject::Key(KeyKind::Golden),
+                    'k' => MapObject::Key(KeyKind::Silver),
+                    'D' => MapObject::Door(KeyKind::Golden),
+                    'd' => MapObject::Door(KeyKind::Silver),
+                    _ => MapObject::Plain,
+                })
+                .collect()
+        })
+        .collect()
+}
+
 pub struct Plugin;
 
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(SystemSet::on_enter(game::State::Play).with_system(setup))
-            .add_system_set(SystemSet::on_exit(game::State::Play).with_system(cleanup));
+        app.add_system_set(SystemSet::on_enter(game::State::Play).with_system(setup));
     }
 }
 
-fn get_vector_for_tile(x: usize, y: usize, z: f32) -> Vec3 {
+/// Ids of all levels in order, the first one is 1.
+pub fn levels() -> impl Iterator<Item = usize> {
+    (1..).take_while(|&level| MapDefinition::for_level(level).is_some())
+}
+
+/// Inverse of [`get_vector_for_tile`], fractional grid coordinates of a
+/// world position.
+#[allow(clippy::cast_precision_loss)]
+pub fn grid_position(world: Vec2) -> Vec2 {
+    Vec2::new(world.x / TILE_WIDTH as f32, -world.y / TILE_HEIGHT as f32)
+}
+
+pub fn get_vector_for_tile(x: usize, y: usize, z: f32) -> Vec3 {
     let multiplier = Vec3::new(TILE_WIDTH as f32, -(TILE_HEIGHT as f32), 1.);
     let vector = Vec3::new(x as f32, y as f32, z);
     return multiplier * vector;
 }
 
-const FENCE_Z_INDEX: f32 = 2.;
-
 fn create_fence_sprite_bundle(
     commands: &mut Commands,
-    asset: Handle<Image>,
+    fences: &Tileset,
+    tile: &str,
     tile_point_x: usize,
     tile_point_y: usize,
 ) {
     commands
-        .spawn_bundle(SpriteBundle {
-            texture: asset,
+        .spawn_bundle(SpriteSheetBundle {
+            texture_atlas: fences.atlas.clone(),
+            sprite: fences.sprite(tile),
             transform: Transform::from_translation(get_vector_for_tile(
                 tile_point_x,
                 tile_point_y,
-                FENCE_Z_INDEX,
+                1.,
             )),
             ..default()
         })
-        .insert(Collidable { can_move: false });
+        .insert(YSort::for_height(SPRITE_HEIGHT))
+        .insert(Collidable { can_move: false })
+        .insert(StateScoped(game::State::Play));
+}
+
+/// Spawns a sprite lying flat on the ground, under anything standing on it.
+fn spawn_flat(commands: &mut Commands, texture: &Handle<Image>, x: usize, y: usize) -> Entity {
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: texture.clone(),
+            transform: Transform::from_translation(get_vector_for_tile(x, y, 0.1)),
+            ..default()
+        })
+        .insert(StateScoped(game::State::Play))
+        .id()
+}
+
+/// Spawns a sprite standing on the ground, sorted with the characters.
+fn spawn_standing(commands: &mut Commands, texture: &Handle<Image>, x: usize, y: usize) -> Entity {
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: texture.clone(),
+            transform: Transform::from_translation(get_vector_for_tile(x, y, 1.)),
+            ..default()
+        })
+        .insert(YSort::for_height(SPRITE_HEIGHT))
+        .insert(StateScoped(game::State::Play))
+        .id()
 }
 
-fn spawn_border_fences(
+fn spawn_map_object(
     commands: &mut Commands,
-    assets: &Res<AssetServer>,
-    tile_point_x: usize,
-    tile_point_y: usize,
+    sprites: &Sprites,
+    tilesets: &Tilesets,
     map_def: &MapDefinition,
+    x: usize,
+    y: usize,
 ) {
-    if tile_point_x == 0 && tile_point_y == 0 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Corner_Bottom_Right.png"),
-            tile_point_x,
-            tile_point_y,
-        );
-    } else if tile_point_x == 0 && tile_point_y == map_def.height - 1 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Corner_Top_Right.png"),
-            tile_point_x,
-            tile_point_y,
-        );
-    } else if tile_point_y == 0 && tile_point_x == map_def.width - 1 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Corner_Bottom_Left.png"),
-            tile_point_x,
-            tile_point_y,
-        );
-    } else if tile_point_y == map_def.height - 1 && tile_point_x == map_def.width - 1 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Corner_Top_Left.png"),
-            tile_point_x,
-            tile_point_y,
-        );
-    } else if tile_point_x == 0 || tile_point_x == map_def.width - 1 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Vertical.png"),
-            tile_point_x,
-            tile_point_y,
-        );
-    } else if tile_point_y == 0 || tile_point_y == map_def.height - 1 {
-        create_fence_sprite_bundle(
-            commands,
-            assets.load("sprites/Fences/Fence_Horizontal.png"),
-            tile_point_x,
-            tile_point_y,
-        );
+    let hazards = &sprites.hazards;
+    // Picks between variations of a tile so repeated tiles don't look tiled.
+    let variation = x + y;
+
+    match map_def.object(x, y) {
+        Some(MapObject::Fence) => {
+            create_fence_sprite_bundle(
+                commands,
+                &tilesets.fences,
+                fences::tile_name(map_def, x, y),
+                x,
+                y,
+            );
+        }
+        Some(MapObject::Hole) => {
+            spawn_flat(commands, &sprites.hole, x, y);
+        }
+        Some(MapObject::Coop) => {
+            let coop = spawn_standing(commands, &sprites.coop, x, y);
+            commands.entity(coop).insert(Coop);
+        }
+        Some(MapObject::Spikes) => {
+            let spikes = spawn_flat(commands, &hazards.spikes, x, y);
+            commands.entity(spikes).insert(Spikes);
+        }
+        Some(MapObject::Goo) => {
+            spawn_flat(commands, &hazards.goo[variation % hazards.goo.len()], x, y);
+        }
+        Some(MapObject::Ice) => {
+            spawn_flat(commands, &hazards.ice[variation % hazards.ice.len()], x, y);
+        }
+        Some(MapObject::Water) => {
+            spawn_flat(commands, &hazards.water, x, y);
+        }
+        Some(MapObject::Bridge) => {
+            spawn_flat(commands, &hazards.bridge, x, y);
+        }
+        Some(MapObject::WaterSign) => {
+            let sign = spawn_standing(commands, &hazards.water_sign, x, y);
+            commands.entity(sign).insert(Collidable { can_move: false });
+        }
+        Some(MapObject::Fountain) => {
+            let fountain = spawn_standing(commands, &sprites.fountain, x, y);
+            commands
+                .entity(fountain)
+                .insert(Fountain)
+                .insert(Collidable { can_move: false });
+        }
+        Some(MapObject::GardenBed(_)) => {
+            garden::spawn_bed(commands, sprites, x, y);
+        }
+        Some(MapObject::Key(kind)) => {
+            let key = spawn_standing(commands, kind.key_texture(sprites), x, y);
+            commands.entity(key).insert(Key(*kind));
+        }
+        Some(MapObject::Door(kind)) => {
+            let door = spawn_standing(commands, kind.door_texture(sprites), x, y);
+            commands
+                .entity(door)
+                .insert(Door {
+                    kind: *kind,
+                    tile: (x, y),
+                })
+                .insert(Collidable { can_move: false });
+        }
+        Some(MapObject::Plain) | None => {}
     }
 }
 
-fn setup(mut commands: Commands, assets: Res<AssetServer>) {
-    let map_def = MapDefinition::new();
+fn setup(
+    mut commands: Commands,
+    sprites: Res<Sprites>,
+    tilesets: Res<Tilesets>,
+    selected: Res<game::SelectedLevel>,
+    minimap_settings: Res<MinimapSettings>,
+) {
+    let level = selected.0;
+    let (level, map_def) = if let Some(map_def) = MapDefinition::for_level(level) {
+        (level, map_def)
+    } else {
+        warn!("Level {} does not exist, starting level 1 instead", level);
+        (1, MapDefinition::new())
+    };
+    commands.insert_resource(CurrentLevel {
+        id: level,
+        egg_goal: map_def.egg_goal,
+        camera_scale: map_def.camera_scale,
+    });
+    commands.insert_resource(map_def.bounds());
+    commands.insert_resource(Basket::new(map_def.basket_capacity));
+    commands.insert_resource(KeyRing::default());
+    commands.insert_resource(PredatorSpawner::new(map_def.predator_interval));
+    let grid = NavGrid::new(&map_def);
+    commands.insert_resource(map_def.clone());
+    minimap::spawn(&mut commands, &map_def, &minimap_settings);
+
     for tile_point_x in 0..map_def.width {
         for tile_point_y in 0..map_def.height {
-            commands.spawn_bundle(SpriteBundle {
-                texture: assets.load("sprites/Terrain_Flat/Grass_Dark.png"),
-                transform: Transform::from_translation(get_vector_for_tile(
-                    tile_point_x,
-                    tile_point_y,
-                    0.0001 * (tile_point_x as f32) + 0.000_001 * (tile_point_y as f32),
-                )),
-                sprite: Sprite {
-                    anchor: bevy::sprite::Anchor::Center,
+            commands
+                .spawn_bundle(SpriteSheetBundle {
+                    texture_atlas: tilesets.ground.atlas.clone(),
+                    sprite: tilesets.ground.sprite("ground_05"),
+                    transform: Transform::from_translation(get_vector_for_tile(
+                        tile_point_x,
+                        tile_point_y,
+                        0.0001 * (tile_point_x as f32) + 0.000_001 * (tile_point_y as f32),
+                    )),
                     ..default()
-                },
-                ..default()
-            });
-
-            // Spawn map borders
-            spawn_border_fences(&mut commands, &assets, tile_point_x, tile_point_y, &map_def);
-
-            match map_def.map_objects[tile_point_y].get(tile_point_x) {
-                Some(MapObject::Hole) => {
-                    commands.spawn_bundle(SpriteBundle {
-                        texture: assets.load("sprites/Objects/Hole.png"),
-                        transform: Transform::from_translation(get_vector_for_tile(
-                            tile_point_x,
-                            tile_point_y,
-                            0.1,
-                        )),
-                        ..default()
-                    });
-                }
-                _ => {}
-            }
+                })
+                .insert(StateScoped(game::State::Play));
+
+            spawn_map_object(
+                &mut commands,
+                &sprites,
+                &tilesets,
+                &map_def,
+                tile_point_x,
+                tile_point_y,
+            );
         }
     }
 
     // Spawn player
+    let player_position = get_vector_for_tile(map_def.player_spawn.0, map_def.player_spawn.1, 1.);
     commands
         .spawn_bundle(SpriteBundle {
-            texture: assets.load("player.png"),
-            transform: Transform::from_translation(get_vector_for_tile(
-                map_def.player_spawn.0,
-                map_def.player_spawn.1,
-                1.,
-            )),
+            texture: sprites.cat.down.clone(),
+            transform: Transform::from_translation(player_position),
             ..default()
         })
         .insert(Player)
+        .insert(TerrainMotion::default())
+        .insert(Character::Cat)
+        .insert(Facing::Down)
+        .insert(Walk::new(player_position))
+        .insert(YSort::for_height(SPRITE_HEIGHT))
         .insert(Collidable { can_move: false })
-        .insert(GameplayObject);
+        .insert(StateScoped(game::State::Play));
 
     // Spawn doggy
+    let pet_position =
+        get_vector_for_tile(map_def.player_spawn.0 + 1, map_def.player_spawn.1 + 1, 1.);
     commands
         .spawn_bundle(SpriteBundle {
-            texture: assets.load("sprites/Characters/Fox_Left.png"),
-            transform: Transform::from_translation(get_vector_for_tile(
-                map_def.player_spawn.0 + 1,
-                map_def.player_spawn.1 + 1,
-                1.,
-            )),
+            texture: sprites.fox.left.clone(),
+            transform: Transform::from_translation(pet_position),
             ..default()
         })
         .insert(Pet)
+        .insert(PetCommand::Follow)
+        .insert(TerrainMotion::default())
+        .insert(Character::Fox)
+        .insert(Facing::Left)
+        .insert(Walk::new(pet_position))
+        .insert(YSort::for_height(SPRITE_HEIGHT))
         .insert(Collidable { can_move: true })
-        .insert(GameplayObject);
-
-    // Spawn chickens
-    for chicken_spawn in map_def.chicken_spawns {
-        commands
-            .spawn_bundle(SpriteBundle {
-                texture: assets.load("Chick_Down.png"),
-                transform: Transform::from_translation(get_vector_for_tile(
-                    chicken_spawn.0,
-                    chicken_spawn.1,
-                    1.,
-                )),
-                ..default()
-            })
-            .insert(Chicken {
-                egg_timer: Timer::new(CHICKEN_EGG_COOLDOWN, true),
-            })
-            .insert(Collidable { can_move: true })
-            .insert(GameplayObject);
+        .insert(StateScoped(game::State::Play))
+        .with_children(|parent| pet::spawn_bubble(parent, &sprites));
+
+    // Spawn chickens, the ones the player can't get to yet need rescuing.
+    for &(x, y, breed) in &map_def.chicken_spawns {
+        let position = get_vector_for_tile(x, y, 1.);
+        let chicken = spawn_chicken(&mut commands, &sprites, position, breed);
+        if !map_def.reachable(&grid, position.truncate()) {
+            commands.entity(chicken).insert(Trapped);
+        }
     }
+    commands.insert_resource(grid);
+}
+
+pub fn spawn_chicken(
+    commands: &mut Commands,
+    sprites: &Sprites,
+    position: Vec3,
+    breed: Breed,
+) -> Entity {
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: sprites.chick.down.clone(),
+            transform: Transform::from_translation(position),
+            ..default()
+        })
+        .insert(Character::Chick)
+        .insert(Facing::Down)
+        .insert(Walk::new(position))
+        .insert(YSort::for_height(SPRITE_HEIGHT))
+        .insert(TerrainMotion::default())
+        .insert(Chicken {
+            breed,
+            egg_timer: Timer::new(CHICKEN_EGG_COOLDOWN, true),
+        })
+        .insert(Needs::default())
+        .insert(Wander::default())
+        .insert(Collidable { can_move: true })
+        .insert(StateScoped(game::State::Play))
+        .with_children(|parent| needs::spawn_bubble(parent, sprites))
+        .id()
 }
 
-fn cleanup() {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tile(x: usize, y: usize) -> Vec2 {
+        get_vector_for_tile(x, y, 0.).truncate()
+    }
+
+    #[test]
+    fn holes_block_paths() {
+        let map = MapDefinition {
+            width: 3,
+            height: 1,
+            player_spawn: (0, 0),
+            map_objects: parse_layout(&[".o."]),
+            ..MapDefinition::new()
+        };
+
+        let grid = NavGrid::new(&map);
+
+        assert!(!map.reachable(&grid, tile(2, 0)));
+    }
+
+    #[test]
+    fn coops_and_keys_can_be_reached_on_every_level() {
+        for level in levels() {
+            let map = MapDefinition::for_level(level).unwrap();
+            let grid = NavGrid::new(&map);
+
+            for (x, y) in (0..map.height).flat_map(|y| (0..map.width).map(move |x| (x, y))) {
+                if matches!(map.object(x, y), Some(MapObject::Coop | MapObject::Key(_))) {
+                    assert!(
+                        map.reachable(&grid, tile(x, y)),
+                        "level {level} tile {x},{y}"
+                    );
+                }
+            }
+        }
+    }
+}
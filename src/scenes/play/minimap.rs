@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::game::{self, StateScoped};
+
+use super::{
+    hatching::Chick,
+    maps::{self, MapDefinition, MapObject},
+    predators::Predator,
+    Chicken, Egg, Pet, Player,
+};
+
+const DEFAULT_WIDTH: f32 = 240.0;
+const MARGIN: f32 = 16.0;
+const DOT_SIZE: f32 = 8.0;
+const CHICK_DOT_SIZE: f32 = 5.0;
+const TOGGLE_KEY: KeyCode = KeyCode::M;
+
+const BACKGROUND_COLOR: Color = Color::rgba(0.11, 0.11, 0.11, 0.75);
+const FENCE_COLOR: Color = Color::rgb(0.6, 0.42, 0.2);
+const HOLE_COLOR: Color = Color::rgb(0.05, 0.05, 0.05);
+const COOP_COLOR: Color = Color::rgb(0.3, 0.55, 1.0);
+const SPIKES_COLOR: Color = Color::rgb(0.6, 0.6, 0.65);
+const GOO_COLOR: Color = Color::rgb(0.45, 0.75, 0.2);
+const ICE_COLOR: Color = Color::rgb(0.75, 0.9, 1.0);
+const WATER_COLOR: Color = Color::rgb(0.15, 0.35, 0.8);
+const BRIDGE_COLOR: Color = Color::rgb(0.45, 0.3, 0.15);
+const FOUNTAIN_COLOR: Color = Color::rgb(0.4, 0.8, 0.95);
+const GARDEN_COLOR: Color = Color::rgb(0.35, 0.55, 0.2);
+const DOOR_COLOR: Color = Color::rgb(0.85, 0.65, 0.1);
+const PLAYER_COLOR: Color = Color::rgb(1.0, 0.13, 0.13);
+const PET_COLOR: Color = Color::rgb(1.0, 0.55, 0.1);
+const CHICKEN_COLOR: Color = Color::rgb(0.95, 0.95, 0.95);
+const CHICK_COLOR: Color = Color::rgb(1.0, 0.95, 0.55);
+const EGG_COLOR: Color = Color::rgb(1.0, 0.85, 0.1);
+const PREDATOR_COLOR: Color = Color::rgb(0.6, 0.2, 0.8);
+
+/// Minimap width in pixels and whether it is shown, kept between levels.
+pub struct MinimapSettings {
+    pub width: f32,
+    pub visible: bool,
+}
+
+impl Default for MinimapSettings {
+    fn default() -> MinimapSettings {
+        MinimapSettings {
+            width: DEFAULT_WIDTH,
+            visible: true,
+        }
+    }
+}
+
+/// Pixel size of the minimap and of one map tile on it.
+#[derive(Component)]
+pub struct Minimap {
+    tile_size: Vec2,
+}
+
+/// Square drawn for a door, removed once the door is opened.
+#[derive(Component)]
+pub struct MinimapDoor(usize, usize);
+
+/// Dot drawn on the minimap for a world entity.
+#[derive(Component)]
+pub struct MinimapDot(Entity);
+
+fn display(visible: bool) -> Display {
+    if visible {
+        Display::Flex
+    } else {
+        Display::None
+    }
+}
+
+fn square(position: Vec2, size: Vec2, color: Color) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            position_type: PositionType::Absolute,
+            position: Rect {
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                ..Default::default()
+            },
+            size: Size::new(Val::Px(size.x), Val::Px(size.y)),
+            ..Default::default()
+        },
+        color: color.into(),
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn spawn(commands: &mut Commands, map: &MapDefinition, settings: &MinimapSettings) {
+    let grid = Vec2::new(map.width as f32, map.height as f32);
+    let aspect = (grid.y * maps::TILE_HEIGHT as f32) / (grid.x * maps::TILE_WIDTH as f32);
+    let size = Vec2::new(settings.width, settings.width * aspect);
+    let tile_size = size / grid;
+
+    let mut background = square(Vec2::ZERO, size, BACKGROUND_COLOR);
+    background.style.position = Rect {
+        right: Val::Px(MARGIN),
+        bottom: Val::Px(MARGIN),
+        ..Default::default()
+    };
+    background.style.display = display(settings.visible);
+
+    commands
+        .spawn_bundle(background)
+        .insert(Minimap { tile_size })
+        .insert(StateScoped(game::State::Play))
+        .with_children(|parent| {
+            for y in 0..map.height {
+                for x in 0..map.width {
+                    let color = match map.object(x, y) {
+                        Some(MapObject::Fence) => FENCE_COLOR,
+                        Some(MapObject::Hole) => HOLE_COLOR,
+                        Some(MapObject::Coop) => COOP_COLOR,
+                        Some(MapObject::Spikes) => SPIKES_COLOR,
+                        Some(MapObject::Goo) => GOO_COLOR,
+                        Some(MapObject::Ice) => ICE_COLOR,
+                        Some(MapObject::Water | MapObject::WaterSign) => WATER_COLOR,
+                        Some(MapObject::Bridge) => BRIDGE_COLOR,
+                        Some(MapObject::Fountain) => FOUNTAIN_COLOR,
+                        Some(MapObject::GardenBed(_)) => GARDEN_COLOR,
+                        Some(MapObject::Door(_)) => DOOR_COLOR,
+                        Some(MapObject::Plain | MapObject::Key(_)) | None => continue,
+                    };
+                    let position = Vec2::new(x as f32, y as f32) * tile_size;
+
+                    let mut tile = parent.spawn_bundle(square(position, tile_size, color));
+                    if let Some(MapObject::Door(_)) = map.object(x, y) {
+                        tile.insert(MinimapDoor(x, y));
+                    }
+                }
+            }
+        });
+}
+
+pub fn toggle_minimap(
+    keys: Res<Input<KeyCode>>,
+    mut settings: ResMut<MinimapSettings>,
+    mut minimap: Query<&mut Style, With<Minimap>>,
+) {
+    if !keys.just_pressed(TOGGLE_KEY) {
+        return;
+    }
+
+    settings.visible = !settings.visible;
+    for mut style in minimap.iter_mut() {
+        style.display = display(settings.visible);
+    }
+}
+
+pub fn remove_opened_doors(
+    mut commands: Commands,
+    map: Res<MapDefinition>,
+    doors: Query<(Entity, &MinimapDoor)>,
+) {
+    if !map.is_changed() {
+        return;
+    }
+
+    for (entity, door) in doors.iter() {
+        if !matches!(map.object(door.0, door.1), Some(MapObject::Door(_))) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn update_minimap_dots(
+    mut commands: Commands,
+    minimap: Query<(Entity, &Minimap)>,
+    tracked: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&Player>,
+            Option<&Pet>,
+            Option<&Chicken>,
+            Option<&Chick>,
+            Option<&Predator>,
+        ),
+        Or<(
+            With<Player>,
+            With<Pet>,
+            With<Chicken>,
+            With<Chick>,
+            With<Predator>,
+            With<Egg>,
+        )>,
+    >,
+    mut dots: Query<(Entity, &MinimapDot, &mut Style)>,
+) {
+    let Ok((minimap_entity, minimap)) = minimap.get_single() else {
+        return;
+    };
+    let dot_position = |transform: &Transform, size: f32| {
+        let grid = maps::grid_position(transform.translation.truncate());
+        (grid + 0.5) * minimap.tile_size - size / 2.0
+    };
+
+    let mut with_dot = HashSet::new();
+    for (dot_entity, dot, mut style) in dots.iter_mut() {
+        if let Ok((_, transform, .., chick, _)) = tracked.get(dot.0) {
+            let size = if chick.is_some() {
+                CHICK_DOT_SIZE
+            } else {
+                DOT_SIZE
+            };
+            let position = dot_position(transform, size);
+            style.position.left = Val::Px(position.x);
+            style.position.top = Val::Px(position.y);
+            with_dot.insert(dot.0);
+        } else {
+            commands.entity(dot_entity).despawn_recursive();
+        }
+    }
+
+    for (entity, transform, player, pet, chicken, chick, predator) in tracked.iter() {
+        if with_dot.contains(&entity) {
+            continue;
+        }
+
+        let (color, size) = match (player, pet, chicken, chick, predator) {
+            (Some(_), ..) => (PLAYER_COLOR, DOT_SIZE),
+            (_, Some(_), ..) => (PET_COLOR, DOT_SIZE),
+            (_, _, Some(_), ..) => (CHICKEN_COLOR, DOT_SIZE),
+            (_, _, _, Some(_), _) => (CHICK_COLOR, CHICK_DOT_SIZE),
+            (.., Some(_)) => (PREDATOR_COLOR, DOT_SIZE),
+            _ => (EGG_COLOR, DOT_SIZE),
+        };
+        let dot = commands
+            .spawn_bundle(square(
+                dot_position(transform, size),
+                Vec2::splat(size),
+                color,
+            ))
+            .insert(MinimapDot(entity))
+            .id();
+        commands.entity(minimap_entity).add_child(dot);
+    }
+}
@@ -1,52 +1,189 @@
 use std::time::Duration;
 
-use crate::{game, resources::prelude::Fonts};
-use bevy::prelude::{Plugin as BevyPlugin, *};
+use crate::{
+    config::Args,
+    game::{self, LevelResult, SaveData, StateScoped},
+    resources::prelude::{Fonts, GameRng, Sprites},
+};
+use bevy::{
+    prelude::{Plugin as BevyPlugin, *},
+    transform::TransformSystem,
+};
 use rand::Rng;
 
+mod animation;
+mod basket;
+mod camera;
+mod depth;
+mod eggs;
+mod feedback;
+mod garden;
+mod hatching;
+mod hazards;
+mod indicators;
+mod keys;
 mod maps;
+mod minimap;
+mod navigation;
+mod needs;
+mod pet;
+mod predators;
 mod ui;
 
+use animation::{Character, Facing, Walk};
+use basket::Basket;
+use camera::ShakeEvent;
+use depth::YSort;
+use eggs::{Breed, EggKind};
+use feedback::{CropsSold, EggCollected, EggsBanked};
+use maps::MapDefinition;
+
+pub use maps::levels;
+
 // TODO Throw stick for dog
 // TODO Chicken should get stuck in the holes
-// TODO UI Display scores and objectives
 //
+/// Points from delivered eggs, crop sales are kept apart in
+/// [`garden::CropBonus`].
 #[derive(Default)]
 pub struct CollectedCoins(usize);
 
+/// Eggs delivered to a coop, what the level egg goal counts.
+#[derive(Default)]
+pub struct DeliveredEggs(usize);
+
+/// Trapped chickens freed during the level.
+#[derive(Default)]
+pub struct ChickensRescued(usize);
+
 #[derive(Default)]
 pub struct LevelTimer {
     timer: Timer,
 }
 
+/// Systems drawing from [`GameRng`] take turns in this order, so the same
+/// seed plays out the same way every time.
+#[derive(SystemLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RngStep {
+    Garden,
+    Lay,
+    Hatch,
+    Predators,
+    Wander,
+}
+
+pub struct CurrentLevel {
+    id: usize,
+    egg_goal: usize,
+    camera_scale: f32,
+}
+
 pub struct Plugin;
 
 impl BevyPlugin for Plugin {
     fn build(&self, app: &mut App) {
+        let mut minimap_settings = minimap::MinimapSettings::default();
+        match app
+            .world
+            .get_resource::<Args>()
+            .and_then(|args| args.minimap_width)
+        {
+            Some(0) => minimap_settings.visible = false,
+            #[allow(clippy::cast_precision_loss)]
+            Some(width) => minimap_settings.width = width as f32,
+            None => {}
+        }
+
         app.add_plugin(maps::Plugin)
+            .add_plugin(garden::Plugin)
+            .add_plugin(keys::Plugin)
+            .add_plugin(hatching::Plugin)
+            .add_plugin(predators::Plugin)
             .insert_resource(CollectedCoins(0))
+            .insert_resource(DeliveredEggs(0))
+            .insert_resource(minimap_settings)
+            .add_event::<ShakeEvent>()
+            .add_event::<EggCollected>()
+            .add_event::<EggsBanked>()
+            .add_event::<CropsSold>()
             .add_system_set(SystemSet::on_enter(game::State::Play).with_system(setup))
+            .add_system_set_to_stage(
+                CoreStage::PostUpdate,
+                SystemSet::new()
+                    .with_system(animation::update_facing.label(animation::UpdateFacing))
+                    .with_system(animation::select_sprite.after(animation::UpdateFacing))
+                    .with_system(animation::animate_walk.after(animation::UpdateFacing))
+                    .with_system(depth::y_sort.before(TransformSystem::TransformPropagate)),
+            )
             .add_system_set(
                 SystemSet::on_in_stack_update(game::State::Play)
-                    .with_system(handle_input)
-                    .with_system(chickens_lay_eggs)
+                    .with_system(hazards::record_motion_start.label(hazards::TerrainStep::Record))
+                    .with_system(
+                        handle_input
+                            .after(hazards::TerrainStep::Record)
+                            .before(hazards::TerrainStep::Apply),
+                    )
+                    .with_system(needs::drain_needs.before(chickens_lay_eggs))
+                    .with_system(chickens_lay_eggs.label(RngStep::Lay).after(RngStep::Garden))
+                    .with_system(needs::scatter_feed)
+                    .with_system(
+                        needs::tend_needs
+                            .after(hazards::TerrainStep::Record)
+                            .before(navigation::wander),
+                    )
+                    .with_system(needs::update_need_bubbles)
                     .with_system(player_pickups_eggs)
+                    .with_system(basket::deliver_eggs)
                     .with_system(despawn_timers)
+                    .with_system(indicators::blink_expiring_eggs)
+                    .with_system(feedback::spawn_score_popups)
+                    .with_system(feedback::animate_popups)
+                    .with_system(feedback::punch_counter)
+                    .with_system(indicators::point_to_offscreen_eggs.after(camera::CameraFollow))
                     //        .with_system(chicken_movement) // Has been substitued with collision
                     //        system
-                    .with_system(pet_movement)
-                    .with_system(collision_system)
-                    .with_system(camera_follow_player)
+                    .with_system(navigation::update_nav_grid.before(hazards::TerrainStep::Record))
+                    .with_system(
+                        navigation::wander
+                            .label(RngStep::Wander)
+                            .after(RngStep::Predators)
+                            .after(hazards::TerrainStep::Record)
+                            .before(hazards::TerrainStep::Apply),
+                    )
+                    .with_system(pet::command_pet.before(hazards::TerrainStep::Record))
+                    .with_system(
+                        pet::pet_behaviour
+                            .after(hazards::TerrainStep::Record)
+                            .before(hazards::TerrainStep::Apply),
+                    )
+                    .with_system(pet::update_command_bubble)
+                    .with_system(
+                        collision_system
+                            .after(hazards::TerrainStep::Record)
+                            .before(hazards::TerrainStep::Apply),
+                    )
+                    .with_system(hazards::cycle_spikes.before(hazards::TerrainStep::Apply))
+                    .with_system(hazards::apply_terrain.label(hazards::TerrainStep::Apply))
+                    .with_system(pet_crashes_into_flock)
+                    .with_system(camera::zoom_camera.before(camera::CameraFollow))
+                    .with_system(
+                        camera::camera_follow_player
+                            .label(camera::CameraFollow)
+                            .after(hazards::TerrainStep::Apply),
+                    )
+                    .with_system(camera::shake_camera.after(camera::CameraFollow))
                     .with_system(ui::update_score_system)
-                    .with_system(ui::update_time_system),
-            )
-            .add_system_set(SystemSet::on_exit(game::State::Play).with_system(cleanup));
+                    .with_system(ui::update_time_system)
+                    .with_system(ui::update_chicken_strip)
+                    .with_system(ui::update_key_strip)
+                    .with_system(minimap::toggle_minimap)
+                    .with_system(minimap::update_minimap_dots)
+                    .with_system(minimap::remove_opened_doors)
+                    .with_system(check_level_end),
+            );
     }
 }
 
-#[derive(Component)]
-struct GameplayObject;
-
 #[derive(Component)]
 struct Collidable {
     can_move: bool,
@@ -63,6 +200,7 @@ struct Pet;
 
 #[derive(Component)]
 struct Chicken {
+    breed: Breed,
     egg_timer: Timer,
 }
 
@@ -72,18 +210,25 @@ struct Egg;
 #[derive(Component)]
 struct Despawn(Timer);
 
+const SPRITE_HEIGHT: f32 = 128.;
 const CHICKEN_EGG_COOLDOWN: Duration = Duration::from_secs(10);
 const PLAYER_SPEED: f32 = 350.;
-const EGG_DESPAWN_TIMER: Duration = Duration::from_secs(5);
 const MINIMAL_DISTANCE: f32 = 100. * 100.;
 const CHICKEN_SPEED: f32 = PLAYER_SPEED * 2.;
 const COLLISION_DISTANCE: f32 = 70. * 70.;
 const PICKUP_DISTANCE: f32 = 50. * 50.;
-const PET_DISTANCE: f32 = 120. * 120.;
-const PET_FOLLOW_SPEED: f32 = PLAYER_SPEED * 0.8;
-
-fn setup(mut commands: Commands, fonts: Res<Fonts>) {
-    ui::spawn(&mut commands, &fonts);
+/// Chickens the pet has to bump into at once to count as crashing into the flock.
+const FLOCK_SIZE: usize = 3;
+const FLOCK_CRASH_TRAUMA: f32 = 0.5;
+const ROTTEN_EGG_TRAUMA: f32 = 0.25;
+
+fn setup(mut commands: Commands, fonts: Res<Fonts>, sprites: Res<Sprites>) {
+    ui::spawn(&mut commands, &fonts, &sprites);
+    commands.insert_resource(CollectedCoins(0));
+    commands.insert_resource(DeliveredEggs(0));
+    commands.insert_resource(ChickensRescued::default());
+    commands.insert_resource(hazards::SpikeCycle::default());
+    commands.insert_resource(needs::FeedCooldown::default());
     commands.insert_resource(LevelTimer {
         timer: Timer::new(Duration::from_secs(150), false),
     });
@@ -91,38 +236,47 @@ fn setup(mut commands: Commands, fonts: Res<Fonts>) {
     commands
         .spawn_bundle(camera)
         .insert(MainCamera)
-        .insert(GameplayObject);
+        .insert(camera::Zoom::default())
+        .insert(camera::Shake::default())
+        .insert(StateScoped(game::State::Play));
 }
 
 fn handle_input(
     keys: Res<Input<KeyCode>>,
+    basket: Res<Basket>,
     mut player: Query<&mut Transform, With<Player>>,
     collidables: Query<(&mut Transform, &Collidable), Without<Player>>,
     time: Res<Time>,
 ) {
     let mut transform = player.single_mut();
     let mut movement = Vec2::splat(0.);
+    let speed = PLAYER_SPEED * basket.speed_factor();
 
     if keys.pressed(KeyCode::Up) {
-        movement.y += time.delta_seconds() * PLAYER_SPEED;
+        movement.y += time.delta_seconds() * speed;
     }
 
     if keys.pressed(KeyCode::Down) {
-        movement.y -= time.delta_seconds() * PLAYER_SPEED;
+        movement.y -= time.delta_seconds() * speed;
     }
     if keys.pressed(KeyCode::Left) {
-        movement.x -= time.delta_seconds() * PLAYER_SPEED;
+        movement.x -= time.delta_seconds() * speed;
     }
 
     if keys.pressed(KeyCode::Right) {
-        movement.x += time.delta_seconds() * PLAYER_SPEED;
+        movement.x += time.delta_seconds() * speed;
     }
 
     let next_translation = transform.translation + movement.extend(0.);
     let mut allow_move = true;
     for object in collidables.iter() {
         if !object.1.can_move
-            && object.0.translation.distance_squared(next_translation) < COLLISION_DISTANCE
+            && object
+                .0
+                .translation
+                .truncate()
+                .distance_squared(next_translation.truncate())
+                < COLLISION_DISTANCE
         {
             allow_move = false;
         }
@@ -135,44 +289,68 @@ fn handle_input(
 
 fn player_pickups_eggs(
     mut commands: Commands,
-    mut collected_coins: ResMut<CollectedCoins>,
+    mut basket: ResMut<Basket>,
+    mut collected: EventWriter<EggCollected>,
+    mut shakes: EventWriter<ShakeEvent>,
     player: Query<&Transform, With<Player>>,
-    eggs: Query<(Entity, &Transform), With<Egg>>,
+    eggs: Query<(Entity, &Transform, &EggKind), With<Egg>>,
 ) {
     let player = player.single();
 
-    for (egg_entity, egg_transform) in eggs.iter() {
+    for (egg_entity, egg_transform, kind) in eggs.iter() {
         if player
             .translation
-            .distance_squared(egg_transform.translation)
+            .truncate()
+            .distance_squared(egg_transform.translation.truncate())
             < PICKUP_DISTANCE
+            && basket.put(*kind)
         {
             commands.entity(egg_entity).despawn_recursive();
-            collected_coins.0 += 1;
+            collected.send(EggCollected {
+                position: egg_transform.translation,
+                points: kind.points(),
+                label: kind.label(),
+            });
+            if matches!(kind, EggKind::Rotten) {
+                shakes.send(ShakeEvent {
+                    trauma: ROTTEN_EGG_TRAUMA,
+                });
+            }
         }
     }
 }
 
 fn chickens_lay_eggs(
     mut commands: Commands,
-    mut chickens: Query<(&mut Chicken, &Transform)>,
-    assets: Res<AssetServer>,
+    mut chickens: Query<(&mut Chicken, &needs::Needs, &Transform)>,
+    map: Res<MapDefinition>,
+    sprites: Res<Sprites>,
     time: Res<Time>,
-    mut rng: Local<rand::rngs::OsRng>,
+    mut rng: ResMut<GameRng>,
 ) {
-    for (mut chicken, chicken_transform) in chickens.iter_mut() {
-        if chicken.egg_timer.tick(time.delta()).just_finished() {
-            let mut egg_pos = chicken_transform.translation;
-            egg_pos.z = 1.;
+    for (mut chicken, needs, chicken_transform) in chickens.iter_mut() {
+        let delta = time.delta().mul_f32(needs.well_being());
+        if chicken.egg_timer.tick(delta).just_finished() {
+            let egg_pos = chicken_transform.translation;
+            let kind = map.egg_table.pick(&chicken.breed.egg_table(), &mut rng.0);
             commands
                 .spawn_bundle(SpriteBundle {
-                    texture: assets.load("sprites/Objects/Coin.png"),
-                    transform: Transform::from_translation(egg_pos),
+                    texture: kind.texture(&sprites),
+                    sprite: Sprite {
+                        color: kind.tint(),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(egg_pos)
+                        .with_scale(Vec3::splat(kind.scale())),
                     ..default()
                 })
                 .insert(Egg)
+                .insert(kind)
+                .insert(chicken.breed)
+                .insert(YSort::for_height(SPRITE_HEIGHT))
+                .insert(StateScoped(game::State::Play))
                 .insert(Despawn(Timer::new(
-                    EGG_DESPAWN_TIMER + Duration::from_secs(rng.gen_range(0..=5)),
+                    kind.lifetime() + Duration::from_secs(rng.0.gen_range(0..=5)),
                     false,
                 )));
         }
@@ -223,25 +401,6 @@ fn chicken_movement(
     }
 }
 
-fn pet_movement(
-    mut pet: Query<&mut Transform, (With<Pet>, Without<Player>)>,
-    player: Query<&Transform, With<Player>>,
-    time: Res<Time>,
-) {
-    let mut pet_transform = pet.single_mut();
-    let player_transform = player.single();
-
-    let distance_to_player = pet_transform
-        .translation
-        .distance_squared(player_transform.translation);
-
-    if distance_to_player > PET_DISTANCE {
-        let dir_to_player = (player_transform.translation - pet_transform.translation).normalize();
-
-        pet_transform.translation += dir_to_player * time.delta_seconds() * PET_FOLLOW_SPEED;
-    }
-}
-
 fn despawn_timers(
     mut commands: Commands,
     mut timers: Query<(Entity, &mut Despawn)>,
@@ -254,24 +413,6 @@ fn despawn_timers(
     }
 }
 
-fn camera_follow_player(
-    mut transforms: ParamSet<(
-        Query<&Transform, With<Player>>,
-        Query<&mut Transform, With<MainCamera>>,
-    )>,
-) {
-    let player_transform_query = transforms.p0();
-    let player_translation = player_transform_query.single().translation;
-
-    let mut camera_transform_query = transforms.p1();
-    let mut camera_transform = camera_transform_query.single_mut();
-    *camera_transform = Transform::from_translation(Vec3::new(
-        player_translation.x,
-        player_translation.y,
-        camera_transform.translation.z,
-    ));
-}
-
 fn collision_system(mut transforms: Query<(&mut Transform, &Collidable)>, time: Res<Time>) {
     let mut collidables = transforms.iter_combinations_mut();
     while let Some([mut c1, mut c2]) = collidables.fetch_next() {
@@ -295,8 +436,106 @@ fn collision_system(mut transforms: Query<(&mut Transform, &Collidable)>, time:
     }
 }
 
-fn cleanup(mut commands: Commands, entities: Query<Entity, With<GameplayObject>>) {
-    for entity in entities.iter() {
-        commands.entity(entity).despawn_recursive();
+fn pet_crashes_into_flock(
+    pet: Query<(&Transform, &pet::PetCommand), With<Pet>>,
+    chickens: Query<&Transform, With<Chicken>>,
+    mut shakes: EventWriter<ShakeEvent>,
+    mut crashing: Local<bool>,
+) {
+    let (pet, command) = pet.single();
+    let pet_translation = pet.translation.truncate();
+    let bumped = chickens
+        .iter()
+        .filter(|chicken| {
+            chicken
+                .translation
+                .truncate()
+                .distance_squared(pet_translation)
+                < MINIMAL_DISTANCE
+        })
+        .count();
+
+    let was_crashing = *crashing;
+    // Running into the flock is the point of herding.
+    *crashing = bumped >= FLOCK_SIZE && !command.is_herding();
+    if *crashing && !was_crashing {
+        shakes.send(ShakeEvent {
+            trauma: FLOCK_CRASH_TRAUMA,
+        });
+    }
+}
+
+fn stars_for_time_left(time_left: Duration, total: Duration) -> u8 {
+    let fraction = time_left.as_secs_f32() / total.as_secs_f32();
+
+    if fraction >= 0.5 {
+        3
+    } else if fraction >= 0.25 {
+        2
+    } else {
+        1
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_level_end(
+    mut commands: Commands,
+    mut state: ResMut<State<game::State>>,
+    mut save: ResMut<SaveData>,
+    level: Res<CurrentLevel>,
+    level_timer: Res<LevelTimer>,
+    collected_coins: Res<CollectedCoins>,
+    delivered: Res<DeliveredEggs>,
+    crop_bonus: Res<garden::CropBonus>,
+    rescued: Res<ChickensRescued>,
+) {
+    let completed = delivered.0 >= level.egg_goal;
+
+    if !completed && !level_timer.timer.finished() {
+        return;
+    }
+
+    let time_left = level_timer
+        .timer
+        .duration()
+        .saturating_sub(level_timer.timer.elapsed());
+    let result = LevelResult {
+        level: level.id,
+        score: collected_coins.0,
+        eggs: delivered.0,
+        time_left,
+        stars: if completed {
+            stars_for_time_left(time_left, level_timer.timer.duration())
+        } else {
+            0
+        },
+        crop_bonus: crop_bonus.0,
+        completed,
+        chickens_rescued: rescued.0,
+    };
+
+    save.record(&result);
+    if let Err(error) = save.store() {
+        error!("Failed to write save file: {}", error);
+    }
+
+    commands.insert_resource(result);
+    state.set(game::State::Results).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stars_follow_share_of_time_left() {
+        let total = Duration::from_secs(100);
+
+        assert_eq!(stars_for_time_left(total, total), 3);
+        assert_eq!(stars_for_time_left(Duration::from_secs(50), total), 3);
+        assert_eq!(stars_for_time_left(Duration::from_secs(49), total), 2);
+        assert_eq!(stars_for_time_left(Duration::from_secs(25), total), 2);
+        assert_eq!(stars_for_time_left(Duration::from_secs(24), total), 1);
+        assert_eq!(stars_for_time_left(Duration::ZERO, total), 1);
     }
 }
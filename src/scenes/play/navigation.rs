@@ -0,0 +1,333 @@
+use std::{cmp::Reverse, collections::BinaryHeap, time::Duration};
+
+use bevy::prelude::*;
+use rand::{seq::SliceRandom, Rng};
+
+use crate::resources::prelude::GameRng;
+
+use super::maps::{self, MapDefinition, MapObject};
+
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+/// Extra cost of stepping onto a hazard, paths only cross one when going
+/// around takes much longer.
+const HAZARD_COST: u32 = 30;
+const WANDER_SPEED: f32 = 60.;
+/// How many tiles away a wandering creature picks its next spot.
+const WANDER_RANGE: usize = 2;
+const WANDER_REST_SECS: std::ops::RangeInclusive<u64> = 2..=6;
+/// Close enough to the spot to stop and rest.
+const ARRIVE_DISTANCE: f32 = 8. * 8.;
+
+type Tile = (usize, usize);
+
+/// Which map tiles can be walked and how costly they are, rebuilt whenever
+/// the map changes.
+pub struct NavGrid {
+    width: usize,
+    height: usize,
+    costs: Vec<Option<u32>>,
+}
+
+impl NavGrid {
+    pub fn new(map: &MapDefinition) -> NavGrid {
+        let costs = (0..map.height)
+            .flat_map(|y| (0..map.width).map(move |x| (x, y)))
+            .map(|(x, y)| match map.object(x, y) {
+                Some(
+                    MapObject::Plain
+                    | MapObject::Coop
+                    | MapObject::Bridge
+                    | MapObject::GardenBed(_)
+                    | MapObject::Key(_),
+                ) => Some(STRAIGHT_COST),
+                Some(MapObject::Spikes | MapObject::Goo | MapObject::Ice) => {
+                    Some(STRAIGHT_COST + HAZARD_COST)
+                }
+                Some(
+                    MapObject::Fence
+                    | MapObject::Hole
+                    | MapObject::Water
+                    | MapObject::WaterSign
+                    | MapObject::Fountain
+                    | MapObject::Door(_),
+                )
+                | None => None,
+            })
+            .collect();
+
+        NavGrid {
+            width: map.width,
+            height: map.height,
+            costs,
+        }
+    }
+
+    fn cost(&self, (x, y): Tile) -> Option<u32> {
+        self.costs[y * self.width + x]
+    }
+
+    fn tile(&self, world: Vec2) -> Option<Tile> {
+        let grid = maps::grid_position(world).round();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let tile = (grid.x as usize, grid.y as usize);
+
+        (grid.x >= 0. && grid.y >= 0. && tile.0 < self.width && tile.1 < self.height)
+            .then_some(tile)
+    }
+
+    /// Neighbouring tiles with the cost of stepping onto them, diagonal steps
+    /// may not cut the corner of a blocked tile.
+    fn neighbours(&self, (x, y): Tile, goal: Tile) -> impl Iterator<Item = (Tile, u32)> + '_ {
+        let open = move |tile: Tile| tile == goal || self.cost(tile).is_some();
+
+        [
+            (-1, 0),
+            (1, 0),
+            (0, -1),
+            (0, 1),
+            (-1, -1),
+            (1, -1),
+            (-1, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(dx, dy): (isize, isize)| {
+            let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            if next.0 >= self.width || next.1 >= self.height || !open(next) {
+                return None;
+            }
+            if dx != 0 && dy != 0 && !(open((next.0, y)) && open((x, next.1))) {
+                return None;
+            }
+
+            let step = if dx != 0 && dy != 0 {
+                DIAGONAL_COST
+            } else {
+                STRAIGHT_COST
+            };
+            let terrain = self.cost(next).unwrap_or(STRAIGHT_COST) - STRAIGHT_COST;
+            Some((next, step + terrain))
+        })
+    }
+
+    /// Octile distance, never more than the cheapest path.
+    #[allow(clippy::cast_possible_truncation)]
+    fn estimate((x, y): Tile, (gx, gy): Tile) -> u32 {
+        let (dx, dy) = (x.abs_diff(gx) as u32, y.abs_diff(gy) as u32);
+        let diagonal = dx.min(dy);
+        (dx.max(dy) - diagonal) * STRAIGHT_COST + diagonal * DIAGONAL_COST
+    }
+
+    /// Tiles from the one under `from` to the one under `to`, both included.
+    /// The end tiles may be blocked themselves, a creature standing in a hole
+    /// can still find its way out.
+    pub fn find_path(&self, from: Vec2, to: Vec2) -> Option<Vec<(usize, usize)>> {
+        let (start, goal) = (self.tile(from)?, self.tile(to)?);
+        let index = |(x, y): Tile| y * self.width + x;
+
+        let mut best = vec![u32::MAX; self.costs.len()];
+        let mut came_from: Vec<Option<Tile>> = vec![None; self.costs.len()];
+        let mut open = BinaryHeap::new();
+        best[index(start)] = 0;
+        open.push(Reverse((NavGrid::estimate(start, goal), start)));
+
+        while let Some(Reverse((_, tile))) = open.pop() {
+            if tile == goal {
+                let mut path = vec![goal];
+                while let Some(previous) = came_from[index(*path.last()?)] {
+                    path.push(previous);
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let cost = best[index(tile)];
+            for (next, step) in self.neighbours(tile, goal) {
+                let next_cost = cost + step;
+                if next_cost < best[index(next)] {
+                    best[index(next)] = next_cost;
+                    came_from[index(next)] = Some(tile);
+                    open.push(Reverse((next_cost + NavGrid::estimate(next, goal), next)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Point to head for on the way from `from` to `to`, the middle of the
+    /// next tile on the path or `to` itself once it is around the corner.
+    /// `None` when `to` can't be reached at all.
+    pub fn steer(&self, from: Vec2, to: Vec2) -> Option<Vec2> {
+        match self.find_path(from, to)?.as_slice() {
+            [_, next, _, ..] => Some(maps::get_vector_for_tile(next.0, next.1, 0.).truncate()),
+            _ => Some(to),
+        }
+    }
+
+    /// Walkable tiles around `world`, at most `range` tiles away.
+    fn walkable_near(&self, world: Vec2, range: usize) -> Vec<Tile> {
+        let Some((x, y)) = self.tile(world) else {
+            return Vec::new();
+        };
+        let xs = x.saturating_sub(range)..=(x + range).min(self.width - 1);
+        let ys = y.saturating_sub(range)..=(y + range).min(self.height - 1);
+
+        ys.flat_map(|y| xs.clone().map(move |x| (x, y)))
+            .filter(|&tile| self.cost(tile) == Some(STRAIGHT_COST))
+            .collect()
+    }
+}
+
+/// Creature strolling between nearby spots, resting in between.
+#[derive(Component, Default)]
+pub struct Wander {
+    goal: Option<Vec2>,
+    rest: Timer,
+}
+
+impl Wander {
+    /// Walks to `goal` instead of a spot of its own choosing.
+    pub fn seek(&mut self, goal: Vec2) {
+        self.goal = Some(goal);
+    }
+
+    /// Stays where it is until rested.
+    pub fn stop(&mut self) {
+        self.goal = None;
+    }
+}
+
+pub fn update_nav_grid(map: Res<MapDefinition>, mut grid: ResMut<NavGrid>) {
+    if map.is_changed() {
+        *grid = NavGrid::new(&map);
+    }
+}
+
+pub fn wander(
+    time: Res<Time>,
+    grid: Res<NavGrid>,
+    mut rng: ResMut<GameRng>,
+    mut wanderers: Query<(&mut Transform, &mut Wander)>,
+) {
+    for (mut transform, mut wander) in wanderers.iter_mut() {
+        let position = transform.translation.truncate();
+
+        let Some(goal) = wander.goal else {
+            if wander.rest.tick(time.delta()).finished() {
+                let mut spots = grid.walkable_near(position, WANDER_RANGE);
+                spots.retain(|&(x, y)| {
+                    let spot = maps::get_vector_for_tile(x, y, 0.).truncate();
+                    grid.find_path(position, spot).is_some()
+                });
+                wander.goal = spots
+                    .choose(&mut rng.0)
+                    .map(|&(x, y)| maps::get_vector_for_tile(x, y, 0.).truncate());
+                let rest = Duration::from_secs(rng.0.gen_range(WANDER_REST_SECS));
+                wander.rest = Timer::new(rest, false);
+            }
+            continue;
+        };
+
+        if position.distance_squared(goal) < ARRIVE_DISTANCE {
+            wander.goal = None;
+            continue;
+        }
+
+        // The map changed under it, pick another spot once rested.
+        let Some(waypoint) = grid.steer(position, goal) else {
+            wander.goal = None;
+            continue;
+        };
+        let step = (waypoint - position).clamp_length_max(WANDER_SPEED * time.delta_seconds());
+        transform.translation += step.extend(0.);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Grid from rows of `.` for open ground, `~` for hazards and `#` for
+    /// blocked tiles.
+    fn grid(rows: &[&str]) -> NavGrid {
+        let costs = rows
+            .iter()
+            .flat_map(|row| row.chars())
+            .map(|tile| match tile {
+                '.' => Some(STRAIGHT_COST),
+                '~' => Some(STRAIGHT_COST + HAZARD_COST),
+                _ => None,
+            })
+            .collect();
+
+        NavGrid {
+            width: rows[0].len(),
+            height: rows.len(),
+            costs,
+        }
+    }
+
+    fn at(x: usize, y: usize) -> Vec2 {
+        maps::get_vector_for_tile(x, y, 0.).truncate()
+    }
+
+    #[test]
+    fn estimate_is_octile_distance() {
+        assert_eq!(NavGrid::estimate((0, 0), (0, 0)), 0);
+        assert_eq!(NavGrid::estimate((0, 0), (3, 0)), 3 * STRAIGHT_COST);
+        assert_eq!(NavGrid::estimate((0, 0), (2, 2)), 2 * DIAGONAL_COST);
+        assert_eq!(
+            NavGrid::estimate((4, 1), (0, 3)),
+            2 * DIAGONAL_COST + 2 * STRAIGHT_COST
+        );
+    }
+
+    #[test]
+    fn finds_straight_line() {
+        let grid = grid(&["....."]);
+
+        let path = grid.find_path(at(0, 0), at(4, 0));
+
+        assert_eq!(path, Some(vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 0)]));
+        assert_eq!(grid.steer(at(0, 0), at(4, 0)), Some(at(1, 0)));
+        assert_eq!(grid.steer(at(3, 0), at(4, 0)), Some(at(4, 0)));
+    }
+
+    #[test]
+    fn does_not_cut_corners() {
+        let grid = grid(&["..", "#."]);
+
+        let path = grid.find_path(at(0, 0), at(1, 1));
+
+        assert_eq!(path, Some(vec![(0, 0), (1, 0), (1, 1)]));
+    }
+
+    #[test]
+    fn takes_diagonals_in_the_open() {
+        let grid = grid(&["...", "...", "..."]);
+
+        let path = grid.find_path(at(0, 0), at(2, 2));
+
+        assert_eq!(path, Some(vec![(0, 0), (1, 1), (2, 2)]));
+    }
+
+    #[test]
+    fn walks_around_hazards() {
+        let grid = grid(&["...", ".~.", "..."]);
+
+        let path = grid.find_path(at(0, 1), at(2, 1)).unwrap();
+
+        assert!(!path.contains(&(1, 1)));
+    }
+
+    #[test]
+    fn reports_unreachable_goal() {
+        let grid = grid(&["..#..", "..#.."]);
+
+        assert_eq!(grid.find_path(at(0, 0), at(4, 1)), None);
+        assert_eq!(grid.steer(at(0, 0), at(4, 1)), None);
+        assert_eq!(grid.find_path(at(0, 0), at(9, 9)), None);
+    }
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use bevy::{math::const_vec3, prelude::*};
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::Sprites,
+};
+
+use super::{garden::Harvest, navigation::Wander, Chicken, Despawn, Player, YSort, SPRITE_HEIGHT};
+
+const FEED_KEY: KeyCode = KeyCode::Space;
+const FEED_COOLDOWN: Duration = Duration::from_secs(4);
+/// Uneaten feed rots away after this long.
+const FEED_LIFETIME: Duration = Duration::from_secs(20);
+/// How many full hunger meters a handful of feed fills.
+const FEED_PORTION: f32 = 1.5;
+/// Harvested crops are more filling than plain feed.
+const CROP_PORTION: f32 = 3.;
+const FEED_SCALE: f32 = 0.4;
+/// Share of a full meter lost per second.
+const HUNGER_DRAIN: f32 = 1. / 90.;
+const THIRST_DRAIN: f32 = 1. / 60.;
+/// Share of a full meter refilled per second while eating or drinking.
+const REFILL_RATE: f32 = 0.5;
+/// Below this a chicken complains and goes looking for food or water.
+const NEEDY: f32 = 0.35;
+const EAT_DISTANCE: f32 = 60. * 60.;
+/// Fountains are large, chickens drink from anywhere around them.
+const DRINK_DISTANCE: f32 = 110. * 110.;
+const BUBBLE_OFFSET: Vec3 = const_vec3!([36., 64., 0.01]);
+const BUBBLE_SCALE: f32 = 0.5;
+
+/// How fed and watered a chicken is, from 0 for starving to 1 for full.
+/// Once it starts eating or drinking it keeps at it until full.
+#[derive(Component)]
+pub struct Needs {
+    hunger: f32,
+    thirst: f32,
+    eating: bool,
+    drinking: bool,
+}
+
+impl Default for Needs {
+    fn default() -> Needs {
+        Needs {
+            hunger: 1.,
+            thirst: 1.,
+            eating: false,
+            drinking: false,
+        }
+    }
+}
+
+impl Needs {
+    /// Multiplier for the egg laying rate, a chicken is only as well as its
+    /// most pressing need.
+    pub fn well_being(&self) -> f32 {
+        self.hunger.min(self.thirst)
+    }
+
+    fn hungry(&self) -> bool {
+        self.hunger < NEEDY
+    }
+
+    fn thirsty(&self) -> bool {
+        self.thirst < NEEDY
+    }
+}
+
+/// Handful of feed on the ground, `left` is how much hunger it still fills.
+#[derive(Component)]
+pub struct Feed {
+    left: f32,
+}
+
+#[derive(Component)]
+pub struct Fountain;
+
+/// Bubble above a chicken showing what it misses.
+#[derive(Component)]
+pub struct NeedBubble;
+
+/// Time until the player can scatter feed again.
+pub struct FeedCooldown(Timer);
+
+impl Default for FeedCooldown {
+    fn default() -> FeedCooldown {
+        let mut timer = Timer::new(FEED_COOLDOWN, false);
+        timer.tick(FEED_COOLDOWN);
+
+        FeedCooldown(timer)
+    }
+}
+
+pub fn spawn_bubble(parent: &mut ChildBuilder, sprites: &Sprites) {
+    parent
+        .spawn_bundle(SpriteBundle {
+            texture: sprites.status.starving.clone(),
+            transform: Transform::from_translation(BUBBLE_OFFSET)
+                .with_scale(Vec3::splat(BUBBLE_SCALE)),
+            visibility: Visibility { is_visible: false },
+            ..default()
+        })
+        .insert(NeedBubble);
+}
+
+pub fn drain_needs(time: Res<Time>, mut needs: Query<&mut Needs>) {
+    let delta = time.delta_seconds();
+
+    for mut needs in needs.iter_mut() {
+        needs.hunger = (needs.hunger - HUNGER_DRAIN * delta).max(0.);
+        needs.thirst = (needs.thirst - THIRST_DRAIN * delta).max(0.);
+    }
+}
+
+pub fn scatter_feed(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    time: Res<Time>,
+    sprites: Res<Sprites>,
+    mut cooldown: ResMut<FeedCooldown>,
+    mut harvest: ResMut<Harvest>,
+    player: Query<&Transform, With<Player>>,
+) {
+    cooldown.0.tick(time.delta());
+    if !keys.just_pressed(FEED_KEY) || !cooldown.0.finished() {
+        return;
+    }
+    cooldown.0.reset();
+
+    // Carried crops are fed before plain feed.
+    let (texture, portion) = match harvest.take() {
+        Some(crop) => (crop.texture(&sprites).clone(), CROP_PORTION),
+        None => (sprites.feed.clone(), FEED_PORTION),
+    };
+
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture,
+            transform: Transform::from_translation(player.single().translation)
+                .with_scale(Vec3::splat(FEED_SCALE)),
+            ..default()
+        })
+        .insert(Feed { left: portion })
+        .insert(YSort::for_height(SPRITE_HEIGHT))
+        .insert(Despawn(Timer::new(FEED_LIFETIME, false)))
+        .insert(StateScoped(game::State::Play));
+}
+
+fn nearest<'a>(
+    position: Vec2,
+    points: impl Iterator<Item = (Entity, &'a Transform)>,
+) -> Option<(Entity, Vec2)> {
+    points
+        .map(|(entity, transform)| (entity, transform.translation.truncate()))
+        .min_by(|(_, a), (_, b)| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        })
+}
+
+/// Hungry and thirsty chickens walk to the nearest feed or fountain, eating
+/// and drinking once they get there.
+pub fn tend_needs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut chickens: Query<(&Transform, &mut Needs, &mut Wander), With<Chicken>>,
+    mut feed: Query<(Entity, &Transform, &mut Feed)>,
+    fountains: Query<(Entity, &Transform), With<Fountain>>,
+) {
+    let refill = REFILL_RATE * time.delta_seconds();
+
+    for (transform, mut needs, mut wander) in chickens.iter_mut() {
+        let position = transform.translation.truncate();
+
+        if let Some((_, fountain)) = nearest(position, fountains.iter()) {
+            let near = fountain.distance_squared(position) < DRINK_DISTANCE;
+            needs.drinking &= near;
+            if near && (needs.drinking || needs.thirsty()) {
+                needs.thirst = (needs.thirst + refill).min(1.);
+                needs.drinking = needs.thirst < 1.;
+                wander.stop();
+                continue;
+            } else if needs.thirsty() {
+                wander.seek(fountain);
+                continue;
+            }
+        }
+
+        let closest = nearest(
+            position,
+            feed.iter()
+                .map(|(entity, transform, _)| (entity, transform)),
+        );
+        if let Some((entity, food)) = closest {
+            let near = food.distance_squared(position) < EAT_DISTANCE;
+            needs.eating &= near;
+            if near && (needs.eating || needs.hungry()) {
+                let Ok((_, _, mut feed)) = feed.get_mut(entity) else {
+                    continue;
+                };
+                let eaten = refill.min(feed.left).min(1. - needs.hunger).max(0.);
+                needs.hunger += eaten;
+                feed.left -= eaten;
+                if eaten > 0. && feed.left <= 0. {
+                    commands.entity(entity).despawn_recursive();
+                }
+                needs.eating = needs.hunger < 1. && feed.left > 0.;
+                wander.stop();
+            } else if needs.hungry() {
+                wander.seek(food);
+            }
+        }
+    }
+}
+
+pub fn update_need_bubbles(
+    sprites: Res<Sprites>,
+    chickens: Query<(&Needs, &Children)>,
+    mut bubbles: Query<(&mut Handle<Image>, &mut Visibility), With<NeedBubble>>,
+) {
+    for (needs, children) in chickens.iter() {
+        let texture = if needs.hungry() && needs.hunger <= needs.thirst {
+            Some(&sprites.status.starving)
+        } else if needs.thirsty() {
+            Some(&sprites.status.thirsty)
+        } else {
+            None
+        };
+
+        for child in children.iter() {
+            let Ok((mut handle, mut visibility)) = bubbles.get_mut(*child) else {
+                continue;
+            };
+            if visibility.is_visible != texture.is_some() {
+                visibility.is_visible = texture.is_some();
+            }
+            if let Some(texture) = texture {
+                if *handle != *texture {
+                    *handle = texture.clone();
+                }
+            }
+        }
+    }
+}
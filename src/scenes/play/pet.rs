@@ -0,0 +1,207 @@
+use bevy::{
+    math::{const_vec3, Mat2},
+    prelude::*,
+};
+
+use crate::resources::prelude::Sprites;
+
+use super::{basket::Coop, navigation::NavGrid, Chicken, Collidable, Pet, Player, PLAYER_SPEED};
+
+const FOLLOW_KEY: KeyCode = KeyCode::F;
+const SIT_KEY: KeyCode = KeyCode::S;
+const HERD_KEY: KeyCode = KeyCode::H;
+const GUARD_KEY: KeyCode = KeyCode::G;
+
+const PET_DISTANCE: f32 = 120. * 120.;
+const PET_SPEED: f32 = PLAYER_SPEED * 0.8;
+/// Chickens this close to the player are the ones herded.
+const HERD_SELECT_DISTANCE: f32 = 300. * 300.;
+/// How far from the middle of the flock the pet circles.
+const HERD_RADIUS: f32 = 160.;
+/// Once within this angle behind the flock the pet stops circling and pushes.
+const HERD_PUSH_ANGLE: f32 = 0.5;
+/// Herding is over once the middle of the flock is this close to the target.
+const HERD_DONE_DISTANCE: f32 = 120. * 120.;
+const GUARD_RADIUS: f32 = 200.;
+/// Radians per second while patrolling around the coop.
+const GUARD_TURN_SPEED: f32 = 0.8;
+const BUBBLE_OFFSET: Vec3 = const_vec3!([40., 70., 0.01]);
+const BUBBLE_SCALE: f32 = 0.6;
+
+/// What the player told the pet to do.
+#[derive(Component)]
+pub enum PetCommand {
+    Follow,
+    /// Stays put and blocks whoever walks into it.
+    Sit,
+    /// Gets behind the flock and pushes it toward the target.
+    Herd {
+        flock: Vec<Entity>,
+        target: Vec2,
+    },
+    /// Patrols in a circle around a coop.
+    Guard {
+        coop: Vec2,
+        angle: f32,
+    },
+}
+
+impl PetCommand {
+    fn bubble(&self, sprites: &Sprites) -> Handle<Image> {
+        match self {
+            PetCommand::Follow => sprites.status.happy.clone(),
+            PetCommand::Sit => sprites.status.love.clone(),
+            PetCommand::Herd { .. } => sprites.status.exclamation.clone(),
+            PetCommand::Guard { .. } => sprites.status.angry.clone(),
+        }
+    }
+
+    pub fn is_herding(&self) -> bool {
+        matches!(self, PetCommand::Herd { .. })
+    }
+}
+
+/// Speech bubble above the pet showing its current command.
+#[derive(Component)]
+pub struct CommandBubble;
+
+pub fn spawn_bubble(parent: &mut ChildBuilder, sprites: &Sprites) {
+    parent
+        .spawn_bundle(SpriteBundle {
+            texture: PetCommand::Follow.bubble(sprites),
+            transform: Transform::from_translation(BUBBLE_OFFSET)
+                .with_scale(Vec3::splat(BUBBLE_SCALE)),
+            ..default()
+        })
+        .insert(CommandBubble);
+}
+
+fn nearest<'a>(position: Vec2, points: impl Iterator<Item = &'a Transform>) -> Option<Vec2> {
+    points
+        .map(|transform| transform.translation.truncate())
+        .min_by(|a, b| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        })
+}
+
+pub fn command_pet(
+    keys: Res<Input<KeyCode>>,
+    mut pet: Query<(&Transform, &mut PetCommand, &mut Collidable), With<Pet>>,
+    player: Query<&Transform, With<Player>>,
+    chickens: Query<(Entity, &Transform), With<Chicken>>,
+    coops: Query<&Transform, With<Coop>>,
+) {
+    let (transform, mut command, mut collidable) = pet.single_mut();
+    let position = transform.translation.truncate();
+    let player = player.single().translation.truncate();
+
+    if keys.just_pressed(FOLLOW_KEY) {
+        *command = PetCommand::Follow;
+    } else if keys.just_pressed(SIT_KEY) {
+        *command = PetCommand::Sit;
+    } else if keys.just_pressed(HERD_KEY) {
+        let flock: Vec<Entity> = chickens
+            .iter()
+            .filter(|(_, chicken)| {
+                chicken.translation.truncate().distance_squared(player) < HERD_SELECT_DISTANCE
+            })
+            .map(|(entity, _)| entity)
+            .collect();
+        if let (false, Some(target)) = (flock.is_empty(), nearest(player, coops.iter())) {
+            *command = PetCommand::Herd { flock, target };
+        }
+    } else if keys.just_pressed(GUARD_KEY) {
+        if let Some(coop) = nearest(position, coops.iter()) {
+            let offset = position - coop;
+            *command = PetCommand::Guard {
+                coop,
+                angle: offset.y.atan2(offset.x),
+            };
+        }
+    } else {
+        return;
+    }
+
+    collidable.can_move = !matches!(*command, PetCommand::Sit);
+}
+
+/// Moves `transform` along the grid toward `goal` without overshooting it,
+/// staying put when there is no way there.
+fn step_towards(grid: &NavGrid, transform: &mut Transform, goal: Vec2, distance: f32) {
+    let position = transform.translation.truncate();
+    let Some(waypoint) = grid.steer(position, goal) else {
+        return;
+    };
+    let step = (waypoint - position).clamp_length_max(distance);
+    transform.translation += step.extend(0.);
+}
+
+pub fn pet_behaviour(
+    time: Res<Time>,
+    grid: Res<NavGrid>,
+    mut pet: Query<(&mut Transform, &mut PetCommand), With<Pet>>,
+    player: Query<&Transform, (With<Player>, Without<Pet>)>,
+    chickens: Query<&Transform, (With<Chicken>, Without<Pet>)>,
+) {
+    let (mut transform, mut command) = pet.single_mut();
+    let position = transform.translation.truncate();
+    let step = PET_SPEED * time.delta_seconds();
+
+    match &mut *command {
+        PetCommand::Follow => {
+            let player = player.single().translation.truncate();
+            if position.distance_squared(player) > PET_DISTANCE {
+                step_towards(&grid, &mut transform, player, step);
+            }
+        }
+        PetCommand::Sit => {}
+        PetCommand::Herd { flock, target } => {
+            flock.retain(|chicken| chickens.get(*chicken).is_ok());
+            #[allow(clippy::cast_precision_loss)]
+            let center = flock
+                .iter()
+                .filter_map(|chicken| chickens.get(*chicken).ok())
+                .map(|chicken| chicken.translation.truncate())
+                .fold(Vec2::ZERO, |sum, chicken| sum + chicken)
+                / flock.len().max(1) as f32;
+
+            if flock.is_empty() || center.distance_squared(*target) < HERD_DONE_DISTANCE {
+                *command = PetCommand::Follow;
+                return;
+            }
+
+            let behind = (center - *target).normalize_or_zero();
+            let around = (position - center).normalize_or_zero();
+            let turn = around.perp_dot(behind).atan2(around.dot(behind));
+
+            if turn.abs() > HERD_PUSH_ANGLE {
+                // Circle around the flock instead of running through it.
+                let angle = turn.signum() * (step / HERD_RADIUS).min(turn.abs());
+                let goal = center + Mat2::from_angle(angle) * around * HERD_RADIUS;
+                step_towards(&grid, &mut transform, goal, step);
+            } else {
+                step_towards(&grid, &mut transform, center, step);
+            }
+        }
+        PetCommand::Guard { coop, angle } => {
+            *angle += GUARD_TURN_SPEED * time.delta_seconds();
+            let goal = *coop + Vec2::new(angle.cos(), angle.sin()) * GUARD_RADIUS;
+            step_towards(&grid, &mut transform, goal, step);
+        }
+    }
+}
+
+pub fn update_command_bubble(
+    sprites: Res<Sprites>,
+    pet: Query<&PetCommand, With<Pet>>,
+    mut bubbles: Query<&mut Handle<Image>, With<CommandBubble>>,
+) {
+    let texture = pet.single().bubble(&sprites);
+
+    for mut handle in bubbles.iter_mut() {
+        if *handle != texture {
+            *handle = texture.clone();
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use bevy::prelude::{Plugin as BevyPlugin, *};
+use rand::seq::SliceRandom;
+
+use crate::{
+    game::{self, StateScoped},
+    resources::prelude::{GameRng, Sprites},
+};
+
+use super::{
+    camera::ShakeEvent,
+    hazards::{TerrainMotion, TerrainStep},
+    maps::{self, MapDefinition},
+    navigation::NavGrid,
+    Character, Collidable, Egg, Facing, Pet, RngStep, Walk, YSort, PLAYER_SPEED, SPRITE_HEIGHT,
+};
+
+const PREDATOR_SPEED: f32 = PLAYER_SPEED * 0.55;
+const FLEE_SPEED: f32 = PLAYER_SPEED * 1.1;
+/// Predators run from the pet once it gets this close.
+const FLEE_DISTANCE: f32 = 220. * 220.;
+/// Close enough to the edge tile to slip off the map.
+const EXIT_DISTANCE: f32 = 16. * 16.;
+const EAT_DISTANCE: f32 = 40. * 40.;
+/// Eggs a predator eats before it leaves on its own.
+const APPETITE: usize = 3;
+const EGG_STOLEN_TRAUMA: f32 = 0.2;
+
+/// Sends a predator onto the map now and then, levels without an interval
+/// have no predators.
+pub struct PredatorSpawner {
+    timer: Option<Timer>,
+}
+
+impl PredatorSpawner {
+    pub fn new(interval: Option<Duration>) -> PredatorSpawner {
+        PredatorSpawner {
+            timer: interval.map(|interval| Timer::new(interval, true)),
+        }
+    }
+}
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(
+            SystemSet::on_in_stack_update(game::State::Play)
+                .with_system(
+                    spawn_predators
+                        .label(RngStep::Predators)
+                        .after(RngStep::Hatch),
+                )
+                .with_system(
+                    predators_hunt
+                        .after(TerrainStep::Record)
+                        .before(TerrainStep::Apply),
+                ),
+        );
+    }
+}
+
+#[derive(Component, Default)]
+pub struct Predator {
+    eaten: usize,
+    /// Edge tile it heads for once it fled or had its fill.
+    exit: Option<Vec2>,
+}
+
+fn spawn_predators(
+    mut commands: Commands,
+    mut spawner: ResMut<PredatorSpawner>,
+    mut rng: ResMut<GameRng>,
+    map: Res<MapDefinition>,
+    grid: Res<NavGrid>,
+    sprites: Res<Sprites>,
+    time: Res<Time>,
+) {
+    let Some(timer) = spawner.timer.as_mut() else {
+        return;
+    };
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let Some(&(x, y)) = map.edge_tiles(&grid).choose(&mut rng.0) else {
+        return;
+    };
+    let position = maps::get_vector_for_tile(x, y, 1.);
+
+    commands
+        .spawn_bundle(SpriteBundle {
+            texture: sprites.mouse.down.clone(),
+            transform: Transform::from_translation(position),
+            ..default()
+        })
+        .insert(Predator::default())
+        .insert(Character::Mouse)
+        .insert(Facing::Down)
+        .insert(Walk::new(position))
+        .insert(YSort::for_height(SPRITE_HEIGHT))
+        .insert(TerrainMotion::default())
+        .insert(Collidable { can_move: true })
+        .insert(StateScoped(game::State::Play));
+}
+
+/// Closest edge tile a predator at `position` can walk to.
+fn nearest_exit(map: &MapDefinition, grid: &NavGrid, position: Vec2) -> Option<Vec2> {
+    map.edge_tiles(grid)
+        .into_iter()
+        .map(|(x, y)| maps::get_vector_for_tile(x, y, 0.).truncate())
+        .filter(|&exit| grid.find_path(position, exit).is_some())
+        .min_by(|a, b| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn predators_hunt(
+    mut commands: Commands,
+    time: Res<Time>,
+    map: Res<MapDefinition>,
+    grid: Res<NavGrid>,
+    mut shakes: EventWriter<ShakeEvent>,
+    pet: Query<&Transform, (With<Pet>, Without<Predator>)>,
+    eggs: Query<(Entity, &Transform), (With<Egg>, Without<Predator>)>,
+    mut predators: Query<(Entity, &mut Predator, &mut Transform)>,
+) {
+    let pet = pet.single().translation.truncate();
+
+    for (entity, mut predator, mut transform) in predators.iter_mut() {
+        let position = transform.translation.truncate();
+        let scared = position.distance_squared(pet) < FLEE_DISTANCE;
+
+        if predator.exit.is_none() && (scared || predator.eaten >= APPETITE) {
+            // Walled in predators have nowhere to go but away.
+            predator.exit = nearest_exit(&map, &grid, position).or(Some(position));
+        }
+
+        if let Some(exit) = predator.exit {
+            match grid.steer(position, exit) {
+                Some(waypoint) if position.distance_squared(exit) >= EXIT_DISTANCE => {
+                    let toward = (waypoint - position).normalize_or_zero();
+                    transform.translation +=
+                        (toward * FLEE_SPEED * time.delta_seconds()).extend(0.);
+                }
+                // Off the map through the edge, or the way out got blocked.
+                _ => commands.entity(entity).despawn_recursive(),
+            }
+            continue;
+        }
+
+        let mut targets: Vec<_> = eggs
+            .iter()
+            .map(|(egg, egg_transform)| (egg, egg_transform.translation.truncate()))
+            .collect();
+        targets.sort_by(|(_, a), (_, b)| {
+            a.distance_squared(position)
+                .total_cmp(&b.distance_squared(position))
+        });
+
+        if let Some(&(egg, _)) = targets
+            .first()
+            .filter(|(_, egg)| egg.distance_squared(position) < EAT_DISTANCE)
+        {
+            commands.entity(egg).despawn_recursive();
+            predator.eaten += 1;
+            shakes.send(ShakeEvent {
+                trauma: EGG_STOLEN_TRAUMA,
+            });
+            continue;
+        }
+
+        match targets
+            .iter()
+            .find_map(|&(_, egg)| grid.steer(position, egg))
+        {
+            Some(waypoint) => {
+                let toward = (waypoint - position).normalize_or_zero();
+                transform.translation +=
+                    (toward * PREDATOR_SPEED * time.delta_seconds()).extend(0.);
+            }
+            // Nothing it can get to, it leaves and tries its luck elsewhere.
+            None => predator.exit = nearest_exit(&map, &grid, position).or(Some(position)),
+        }
+    }
+}
@@ -1,53 +1,154 @@
 use bevy::prelude::*;
 
 use crate::{
+    game::{self, StateScoped},
     resources::prelude::*,
-    ui::{Housing, Overlay, SimpleText},
+    ui::{Housing, Overlay, Panel, Progress, SimpleText},
 };
 
-use super::{CollectedCoins, LevelTimer};
+use super::{
+    basket::Basket, feedback::Punch, garden::Harvest, keys::KeyRing, stars_for_time_left, Chicken,
+    CollectedCoins, CurrentLevel, DeliveredEggs, LevelTimer,
+};
+
+const TOOLBAR_HEIGHT: f32 = 90.0;
+const ICON_SIZE: f32 = 48.0;
+const AVATAR_SIZE: f32 = 56.0;
+
+#[derive(Component)]
+pub struct EggCounter;
+
+#[derive(Component)]
+pub struct BasketText;
 
 #[derive(Component)]
-pub struct ScopedMarker;
+pub struct ObjectiveText;
 
 #[derive(Component)]
-pub struct ScoreText;
+pub struct TimerBar;
 
 #[derive(Component)]
 pub struct TimeText;
 
+#[derive(Component)]
+pub struct ChickenStrip;
+
+#[derive(Component)]
+pub struct KeyStrip;
+
+#[derive(Component)]
+pub struct HudPanel;
+
 fn spawn_camera(commands: &mut Commands) {
     commands
         .spawn_bundle(UiCameraBundle::default())
-        .insert(ScopedMarker);
+        .insert(StateScoped(game::State::Play));
+}
+
+fn icon(image: &Handle<Image>, size: f32) -> ImageBundle {
+    ImageBundle {
+        style: Style {
+            size: Size::new(Val::Px(size), Val::Px(size)),
+            margin: Rect::all(Val::Px(4.0)),
+            ..Default::default()
+        },
+        image: image.clone().into(),
+        ..Default::default()
+    }
 }
 
-pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
+pub fn spawn(commands: &mut Commands, fonts: &Fonts, sprites: &Sprites) {
     let font = &fonts.fredoka;
     let overlay = Overlay::new();
-    let mut top = Housing::percent(100.0, 100.0);
-    top.align_items(AlignItems::FlexEnd)
+    let mut hud = Housing::percent(100.0, 100.0);
+    let mut top = Housing::new(Val::Percent(100.0), Val::Px(TOOLBAR_HEIGHT));
+    let mut bottom = Housing::new(Val::Percent(100.0), Val::Px(TOOLBAR_HEIGHT));
+
+    hud.justify_content(JustifyContent::SpaceBetween);
+    top.flex_direction(FlexDirection::Row)
+        .justify_content(JustifyContent::SpaceBetween)
+        .align_items(AlignItems::FlexEnd);
+    bottom
         .flex_direction(FlexDirection::Row)
-        .justify_content(JustifyContent::SpaceBetween);
+        .align_items(AlignItems::FlexStart);
+
+    let toolbar = |width: f32| {
+        Panel::new(
+            &sprites.toolbar_top,
+            Size::new(Val::Px(width), Val::Px(TOOLBAR_HEIGHT)),
+        )
+    };
+    let eggs = toolbar(320.0);
+    let mut objectives = toolbar(420.0);
+    let timer = toolbar(320.0);
+    let strip = || {
+        Panel::new(
+            &sprites.toolbar_bottom,
+            Size::new(Val::Px(AVATAR_SIZE * 2.0), Val::Px(TOOLBAR_HEIGHT)),
+        )
+    };
 
-    let mut loading_text = SimpleText::big("Try to collect as many coins as possible", font);
-    let mut score_text = SimpleText::big("Score", font);
-    let mut time_text = SimpleText::big("TODO time", font);
+    objectives.flex_direction(FlexDirection::ColumnReverse);
+
+    let mut egg_counter = SimpleText::medium("0", font);
+    let mut basket_text = SimpleText::small("", font);
+    let mut objectives_title = SimpleText::small("Objectives", font);
+    let mut objective_text = SimpleText::medium("", font);
+    let mut time_text = SimpleText::medium("", font);
+    let timer_bar = Progress::new(
+        &sprites.bars.green,
+        Size::new(Val::Px(180.0), Val::Px(24.0)),
+    );
 
-    loading_text.color(Colors::PRIMARY);
-    score_text.color(Colors::PRIMARY);
-    time_text.color(Colors::PRIMARY);
+    egg_counter.color(Colors::DARK);
+    basket_text.color(Colors::DARK);
+    objectives_title.color(Colors::DARK);
+    objective_text.color(Colors::DARK);
+    time_text.color(Colors::DARK);
 
     overlay.spawn(
         commands,
         |parent| {
-            top.spawn(parent, |parent| {
-                parent.spawn_bundle(score_text.bundle).insert(ScoreText);
-                loading_text.spawn(parent);
-                parent.spawn_bundle(time_text.bundle).insert(TimeText);
+            hud.spawn(parent, |parent| {
+                top.spawn(parent, |parent| {
+                    eggs.spawn(
+                        parent,
+                        |parent| {
+                            parent.spawn_bundle(icon(&sprites.egg, ICON_SIZE));
+                            parent
+                                .spawn_bundle(egg_counter.bundle)
+                                .insert(EggCounter)
+                                .insert(Punch::default());
+                            parent.spawn_bundle(basket_text.bundle).insert(BasketText);
+                        },
+                        HudPanel,
+                    );
+                    objectives.spawn(
+                        parent,
+                        |parent| {
+                            objectives_title.spawn(parent);
+                            parent
+                                .spawn_bundle(objective_text.bundle)
+                                .insert(ObjectiveText);
+                        },
+                        HudPanel,
+                    );
+                    timer.spawn(
+                        parent,
+                        |parent| {
+                            timer_bar.spawn(parent, TimerBar);
+                            parent.spawn_bundle(time_text.bundle).insert(TimeText);
+                        },
+                        HudPanel,
+                    );
+                });
+                bottom.spawn(parent, |parent| {
+                    strip().spawn(parent, |_| {}, ChickenStrip);
+                    strip().spawn(parent, |_| {}, KeyStrip);
+                });
             });
         },
-        ScopedMarker,
+        StateScoped(game::State::Play),
     );
 
     spawn_camera(commands);
@@ -55,21 +156,128 @@ pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
 
 pub fn update_score_system(
     score: Res<CollectedCoins>,
-    mut text: Query<&mut Text, With<ScoreText>>,
+    delivered: Res<DeliveredEggs>,
+    basket: Res<Basket>,
+    harvest: Res<Harvest>,
+    level: Res<CurrentLevel>,
+    mut texts: ParamSet<(
+        Query<&mut Text, With<EggCounter>>,
+        Query<&mut Text, With<BasketText>>,
+        Query<&mut Text, With<ObjectiveText>>,
+    )>,
 ) {
-    let mut score_text = text.single_mut();
-    score_text.sections[0].value = "Score: ".to_owned() + &score.0.to_string();
+    if !score.is_changed()
+        && !delivered.is_changed()
+        && !basket.is_changed()
+        && !harvest.is_changed()
+        && !level.is_changed()
+    {
+        return;
+    }
+
+    texts.p0().single_mut().sections[0].value =
+        format!("{} / {}   {} pts", delivered.0, level.egg_goal, score.0);
+    texts.p1().single_mut().sections[0].value = match harvest.len() {
+        0 => format!("carrying {}/{}", basket.len(), basket.capacity()),
+        crops => format!(
+            "carrying {}/{}, {crops} crops",
+            basket.len(),
+            basket.capacity()
+        ),
+    };
+
+    let eggs_left = level.egg_goal.saturating_sub(delivered.0);
+    texts.p2().single_mut().sections[0].value = if eggs_left == 0 {
+        "All eggs delivered!".to_string()
+    } else {
+        format!("Deliver {eggs_left} more eggs to the coop")
+    };
 }
 
 pub fn update_time_system(
     mut level_timer: ResMut<LevelTimer>,
+    sprites: Res<Sprites>,
+    mut bar: Query<(&mut Style, &mut UiImage), With<TimerBar>>,
     mut text: Query<&mut Text, With<TimeText>>,
     time: Res<Time>,
 ) {
     level_timer.timer.tick(time.delta());
-    let time_remaining =
-        level_timer.timer.duration().as_secs_f32() - level_timer.timer.elapsed_secs();
+    let total = level_timer.timer.duration();
+    let time_left = total.saturating_sub(level_timer.timer.elapsed());
+
+    let (mut style, mut image) = bar.single_mut();
+    Progress::set(&mut style, time_left.as_secs_f32() / total.as_secs_f32());
+
+    // The bar color tells which rating finishing right now would earn.
+    let fill = match stars_for_time_left(time_left, total) {
+        3 => &sprites.bars.green,
+        2 => &sprites.bars.blue,
+        _ => &sprites.bars.red,
+    };
+    if image.0 != *fill {
+        image.0 = fill.clone();
+    }
+
+    let seconds = time_left.as_secs();
+    text.single_mut().sections[0].value = format!("{}:{:02}", seconds / 60, seconds % 60);
+}
+
+/// Replaces the icons in a HUD strip and sizes it to fit them.
+fn refill_strip(
+    commands: &mut Commands,
+    strip: Entity,
+    style: &mut Style,
+    children: Option<&Children>,
+    icons: &[&Handle<Image>],
+) {
+    for child in children.iter().flat_map(|children| children.iter()) {
+        commands.entity(*child).despawn_recursive();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let width = (icons.len().max(1) as f32 + 1.0) * AVATAR_SIZE;
+    style.size.width = Val::Px(width);
+
+    commands.entity(strip).with_children(|parent| {
+        for image in icons {
+            parent.spawn_bundle(icon(image, AVATAR_SIZE));
+        }
+    });
+}
+
+pub fn update_chicken_strip(
+    mut commands: Commands,
+    sprites: Res<Sprites>,
+    chickens: Query<(), With<Chicken>>,
+    mut strip: Query<(Entity, &mut Style, Option<&Children>), With<ChickenStrip>>,
+) {
+    let (entity, mut style, children) = strip.single_mut();
+    let count = chickens.iter().count();
+    let shown = children.map_or(0, |children| children.len());
+
+    if count == shown {
+        return;
+    }
+
+    let icons = vec![&sprites.chick_avatar; count];
+    refill_strip(&mut commands, entity, &mut style, children, &icons);
+}
+
+pub fn update_key_strip(
+    mut commands: Commands,
+    sprites: Res<Sprites>,
+    ring: Res<KeyRing>,
+    mut strip: Query<(Entity, &mut Style, Option<&Children>), With<KeyStrip>>,
+) {
+    if !ring.is_changed() {
+        return;
+    }
 
-    let mut timer_text = text.single_mut();
-    timer_text.sections[0].value = "Time left: ".to_owned() + &time_remaining.floor().to_string();
+    let (entity, mut style, children) = strip.single_mut();
+    let icons: Vec<_> = ring
+        .keys()
+        .iter()
+        .map(|key| key.key_texture(&sprites))
+        .collect();
+    refill_strip(&mut commands, entity, &mut style, children, &icons);
 }
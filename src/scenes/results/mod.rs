@@ -0,0 +1,66 @@
+mod ui;
+
+use bevy::prelude::{Input, Plugin as BevyPlugin, *};
+
+use crate::{
+    game::{self, LevelResult, SaveData},
+    resources::prelude::*,
+    ui::{ActionKind, ActionMarker},
+};
+
+pub struct Plugin;
+
+impl BevyPlugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_system_set(SystemSet::on_enter(game::State::Results).with_system(setup))
+            .add_system_set(
+                SystemSet::on_update(game::State::Results).with_system(buttons_interactions),
+            );
+    }
+}
+
+fn setup(mut commands: Commands, fonts: Res<Fonts>, result: Res<LevelResult>, save: Res<SaveData>) {
+    ui::spawn(
+        &mut commands,
+        &fonts,
+        &result,
+        save.record_text(result.level),
+    );
+}
+
+fn buttons_interactions(
+    mut mouse_button_input: ResMut<Input<MouseButton>>,
+    mut query: Query<
+        (&ActionMarker, &Interaction, &mut UiColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut app_state: ResMut<State<game::State>>,
+) {
+    for (action, interaction, mut color) in query.iter_mut() {
+        match interaction {
+            Interaction::Clicked => {
+                // workaround for input persistence between states
+                // see: https://github.com/bevyengine/bevy/issues/1700#issuecomment-886999222
+                mouse_button_input.reset(MouseButton::Left);
+
+                match action.kind() {
+                    ActionKind::Play | ActionKind::Retry => {
+                        app_state.set(game::State::Play).unwrap();
+                    }
+                    ActionKind::Title | ActionKind::Quit => {
+                        app_state.set(game::State::Title).unwrap();
+                    }
+                    ActionKind::Level(_) => {}
+                }
+
+                *color = Colors::DARK.into();
+            }
+            Interaction::Hovered => {
+                *color = Colors::LIGHT.into();
+            }
+            Interaction::None => {
+                *color = Colors::PRIMARY.into();
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{self, LevelResult, StateScoped},
+    resources::prelude::*,
+    ui::{Action, ActionMarker, EmbossedText, Housing, Overlay, SimpleText},
+};
+
+fn spawn_camera(commands: &mut Commands) {
+    commands
+        .spawn_bundle(UiCameraBundle::default())
+        .insert(StateScoped(game::State::Results));
+}
+
+pub fn spawn(commands: &mut Commands, fonts: &Fonts, result: &LevelResult, best: String) {
+    let font = &fonts.fredoka;
+    let button_size = Size::new(Val::Px(400.0), Val::Px(50.0));
+
+    let overlay = Overlay::new();
+    let top = Housing::percent(100.0, 30.0);
+    let stats = Housing::percent(100.0, 30.0);
+    let mut actions = Housing::percent(100.0, 40.0);
+
+    let heading = if result.completed {
+        "Level complete!"
+    } else {
+        "Time's up!"
+    };
+    let title = EmbossedText::big(heading, font);
+    let score = SimpleText::medium(format!("Score: {}", result.score), font);
+    let eggs = SimpleText::medium(format!("Eggs delivered: {}", result.eggs), font);
+    let crop_bonus = SimpleText::medium(format!("Crop bonus: {}", result.crop_bonus), font);
+    let time_left = SimpleText::medium(
+        format!("Time left: {}", result.time_left.as_secs_f32().floor()),
+        font,
+    );
+    let rating = SimpleText::medium(format!("Stars: {}/3", result.stars), font);
+    let mut best = SimpleText::small(best, font);
+    let play = Action::new("Play again", font, button_size);
+    let title_action = Action::new("Title", font, button_size);
+
+    best.color(Colors::PRIMARY);
+    actions
+        .justify_content(JustifyContent::SpaceEvenly)
+        .align_items(AlignItems::Center);
+
+    overlay.spawn(
+        commands,
+        |parent| {
+            top.spawn(parent, |parent| {
+                title.spawn(parent);
+            });
+            stats.spawn(parent, |parent| {
+                score.spawn(parent);
+                eggs.spawn(parent);
+                crop_bonus.spawn(parent);
+                time_left.spawn(parent);
+                rating.spawn(parent);
+                best.spawn(parent);
+            });
+            actions.spawn(parent, |parent| {
+                play.spawn(parent, ActionMarker::play());
+                title_action.spawn(parent, ActionMarker::title());
+            });
+        },
+        StateScoped(game::State::Results),
+    );
+
+    spawn_camera(commands);
+}
@@ -6,7 +6,7 @@ use bevy::{
 };
 
 use crate::{
-    game,
+    game::{self, SaveData},
     resources::prelude::*,
     ui::{ActionKind, ActionMarker},
 };
@@ -18,13 +18,12 @@ impl BevyPlugin for Plugin {
         app.add_system_set(SystemSet::on_enter(game::State::Title).with_system(setup))
             .add_system_set(
                 SystemSet::on_update(game::State::Title).with_system(buttons_interactions),
-            )
-            .add_system_set(SystemSet::on_exit(game::State::Title).with_system(cleanup));
+            );
     }
 }
 
-fn setup(mut commands: Commands, fonts: Res<Fonts>) {
-    ui::spawn(&mut commands, &fonts);
+fn setup(mut commands: Commands, fonts: Res<Fonts>, save: Res<SaveData>) {
+    ui::spawn(&mut commands, &fonts, &save);
 }
 
 fn buttons_interactions(
@@ -45,8 +44,9 @@ fn buttons_interactions(
 
                 match action.kind() {
                     ActionKind::Play => {
-                      app_state.set(game::State::Play).unwrap();
+                        app_state.set(game::State::LevelSelect).unwrap();
                     }
+                    ActionKind::Title | ActionKind::Retry | ActionKind::Level(_) => {}
                     ActionKind::Quit => {
                         exit_event.send(AppExit);
                     }
@@ -63,9 +63,3 @@ fn buttons_interactions(
         }
     }
 }
-
-fn cleanup(mut commands: Commands, entities: Query<Entity, With<ui::ScopedMarker>>) {
-    for entity in entities.iter() {
-        commands.entity(entity).despawn_recursive();
-    }
-}
@@ -1,20 +1,18 @@
 use bevy::prelude::*;
 
 use crate::{
+    game::{self, SaveData, StateScoped},
     resources::prelude::*,
     ui::{Action, ActionMarker, EmbossedText, Housing, Overlay, SimpleText},
 };
 
-#[derive(Component)]
-pub struct ScopedMarker;
-
 fn spawn_camera(commands: &mut Commands) {
     commands
         .spawn_bundle(UiCameraBundle::default())
-        .insert(ScopedMarker);
+        .insert(StateScoped(game::State::Title));
 }
 
-pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
+pub fn spawn(commands: &mut Commands, fonts: &Fonts, save: &SaveData) {
     let font = &fonts.fredoka;
     let button_size = Size::new(Val::Px(400.0), Val::Px(50.0));
 
@@ -25,10 +23,12 @@ pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
     let footer = Housing::percent(100.0, 10.0);
 
     let title = EmbossedText::big("Rusty Jam\n\nEgg fetcher", font);
+    let mut stats = SimpleText::small(save.totals_text(), font);
     let notice = SimpleText::small("Created by @silen-z and @michalvankodev", font);
     let play = Action::new("Play", font, button_size);
     let quit = Action::new("Quit", font, button_size);
 
+    stats.color(Colors::PRIMARY);
     actions
         .justify_content(JustifyContent::SpaceEvenly)
         .align_items(AlignItems::Center);
@@ -38,6 +38,7 @@ pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
         |parent| {
             top.spawn(parent, |parent| {
                 title.spawn(parent);
+                stats.spawn(parent);
             });
             bottom.spawn(parent, |parent| {
                 actions.spawn(parent, |parent| {
@@ -49,7 +50,7 @@ pub fn spawn(commands: &mut Commands, fonts: &Fonts) {
                 });
             });
         },
-        ScopedMarker,
+        StateScoped(game::State::Title),
     );
 
     spawn_camera(commands);
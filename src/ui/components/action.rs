@@ -6,7 +6,10 @@ use super::text::Simple;
 
 pub enum Kind {
     Play,
+    Title,
+    Retry,
     Quit,
+    Level(usize),
 }
 
 #[derive(Component)]
@@ -25,11 +28,26 @@ impl Marker {
         Marker::new(Kind::Play)
     }
 
+    #[must_use]
+    pub fn title() -> Marker {
+        Marker::new(Kind::Title)
+    }
+
+    #[must_use]
+    pub fn retry() -> Marker {
+        Marker::new(Kind::Retry)
+    }
+
     #[must_use]
     pub fn quit() -> Marker {
         Marker::new(Kind::Quit)
     }
 
+    #[must_use]
+    pub fn level(level: usize) -> Marker {
+        Marker::new(Kind::Level(level))
+    }
+
     #[must_use]
     pub fn kind(&self) -> &Kind {
         &self.kind
@@ -1,9 +1,15 @@
 mod action;
+mod panel;
+mod progress;
 mod text;
 
 pub use action::Action;
 pub use action::Kind as ActionKind;
 pub use action::Marker as ActionMarker;
 
+pub use panel::Panel;
+
+pub use progress::Progress;
+
 pub use text::Embossed as EmbossedText;
 pub use text::Simple as SimpleText;
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+
+/// Framed box drawn with a stretched image, laying out its children in a row.
+pub struct Panel {
+    bundle: ImageBundle,
+}
+
+impl Default for Panel {
+    fn default() -> Panel {
+        Panel {
+            bundle: ImageBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    padding: Rect::all(Val::Px(16.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Panel {
+    #[must_use]
+    pub fn new(image: &Handle<Image>, size: Size<Val>) -> Panel {
+        let mut panel = Panel::default();
+        panel.bundle.style.size = size;
+        panel.bundle.image = image.clone().into();
+
+        panel
+    }
+
+    pub fn flex_direction(&mut self, flex_direction: FlexDirection) -> &mut Panel {
+        self.bundle.style.flex_direction = flex_direction;
+        self
+    }
+
+    pub fn spawn(
+        self,
+        parent: &mut ChildBuilder,
+        children: impl FnOnce(&mut ChildBuilder),
+        marker: impl Component,
+    ) {
+        parent
+            .spawn_bundle(self.bundle)
+            .with_children(children)
+            .insert(marker);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use crate::resources::prelude::Colors;
+
+pub struct Progress {
+    bundle: NodeBundle,
+    fill: ImageBundle,
+}
+
+impl Default for Progress {
+    fn default() -> Progress {
+        Progress {
+            bundle: NodeBundle {
+                style: Style {
+                    size: Size::new(Val::Px(400.0), Val::Px(24.0)),
+                    justify_content: JustifyContent::FlexStart,
+                    ..Default::default()
+                },
+                color: Colors::DARK.into(),
+                ..Default::default()
+            },
+            fill: ImageBundle {
+                style: Style {
+                    size: Size::new(Val::Percent(0.0), Val::Percent(100.0)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl Progress {
+    #[must_use]
+    pub fn new(image: &Handle<Image>, size: Size<Val>) -> Progress {
+        let mut progress = Progress::default();
+        progress.bundle.style.size = size;
+        progress.fill.image = image.clone().into();
+
+        progress
+    }
+
+    /// Sets how much of the bar is filled, `value` is clamped to `0.0..=1.0`.
+    pub fn set(style: &mut Style, value: f32) {
+        style.size.width = Val::Percent(value.clamp(0.0, 1.0) * 100.0);
+    }
+
+    pub fn spawn(self, parent: &mut ChildBuilder, marker: impl Component) {
+        parent.spawn_bundle(self.bundle).with_children(|parent| {
+            parent.spawn_bundle(self.fill).insert(marker);
+        });
+    }
+}
@@ -63,6 +63,17 @@ impl Simple {
         self
     }
 
+    /// Same text placed in the world instead of the UI, for labels that move
+    /// with the map.
+    #[must_use]
+    pub fn world(self, transform: Transform) -> Text2dBundle {
+        Text2dBundle {
+            text: self.bundle.text,
+            transform,
+            ..Default::default()
+        }
+    }
+
     pub fn spawn(self, parent: &mut ChildBuilder) {
         parent.spawn_bundle(self.bundle);
     }
@@ -30,15 +30,21 @@ impl Overlay {
         Overlay::default()
     }
 
+    pub fn color(&mut self, color: Color) -> &mut Overlay {
+        self.bundle.color = color.into();
+        self
+    }
+
     pub fn spawn(
         self,
         commands: &mut Commands,
         children: impl FnOnce(&mut ChildBuilder),
         marker: impl Component,
-    ) {
+    ) -> Entity {
         commands
             .spawn_bundle(self.bundle)
             .with_children(children)
-            .insert(marker);
+            .insert(marker)
+            .id()
     }
 }